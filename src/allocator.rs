@@ -0,0 +1,91 @@
+use crate::{Binvec, error};
+
+
+// impl allocator
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Finds the first run of `len` free bits, marks it as used, and returns its offset.
+    ///
+    /// The [`Binvec`] is treated as an allocation bitmap where `1` means used and `0` means free,
+    /// and the run is found first-fit with [`find_zero_run`](Binvec::find_zero_run).
+    ///
+    /// ---
+    /// # Arguments
+    /// - `len`: The number of consecutive bits to allocate.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(start)` of the allocated run, whose bits are now `1`. A `len` of `0` allocates nothing and gives `Some(0)`.
+    /// - `None` if there is no run of `len` free bits. The [`Binvec`] is left unchanged.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut blocks = binvec!(16, false);
+    /// assert_eq!(blocks.allocate(4), Some(0));
+    /// assert_eq!(blocks.allocate(8), Some(4));
+    /// assert_eq!(blocks.allocate(8), None);
+    /// assert_eq!(blocks.count_ones(), 12);
+    /// ```
+    /// 
+    pub const fn allocate(&mut self, len: usize) -> Option<usize> {
+        match self.find_zero_run(len) {
+            Some(start) => {
+                let _ = self.set_range(start..start + len); // the run is within bounds
+                Some(start)
+            }
+            None => None,
+        }
+    }
+
+    /// Marks a run of bits returned by [`allocate`](Binvec::allocate) as free again.
+    ///
+    /// Every bit in the run must currently be `1`, so freeing a run twice,
+    /// or freeing a run that overlaps free bits, is reported instead of silently accepted.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `start`: The first bit of the run.
+    /// - `len`: The number of bits in the run.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the run was cleared to `0`.
+    /// - `Err(FreeError::IndexOutOfBounds)` if the run reaches beyond the length `L`.
+    /// - `Err(FreeError::DoubleFree { index })` with the first bit that is already `0`. The [`Binvec`] is left unchanged.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut blocks = binvec!(16, false);
+    /// let start = blocks.allocate(4).unwrap();
+    /// assert_eq!(blocks.free(start, 4), Ok(()));
+    /// assert_eq!(blocks.free(start, 4), Err(error::FreeError::DoubleFree { index: 0 }));
+    /// assert_eq!(blocks.free(14, 4), Err(error::FreeError::IndexOutOfBounds));
+    /// ```
+    /// 
+    pub const fn free(&mut self, start: usize, len: usize) -> Result<(), error::FreeError> {
+        let end: usize = match start.checked_add(len) {
+            Some(end) if end <= L => end,
+            _ => return Err(error::FreeError::IndexOutOfBounds),
+        };
+        match self.count_ones_in_range(start..end) {
+            Some(count) if count == len => {}
+            _ => {
+                let mut index: usize = start;
+                while index < end {
+                    // SAFETY: index is guaranteed to be less than L
+                    if !unsafe { self.get_unchecked(index) } {
+                        return Err(error::FreeError::DoubleFree { index });
+                    }
+                    index += 1;
+                }
+            }
+        }
+        let _ = self.clear_range(start..end); // the run is within bounds
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+use crate::Binvec;
+
+
+// impl Arbitrary
+impl<'a, const L: usize, const N: usize> Arbitrary<'a> for Binvec<L, N> {
+    /// Builds a `Binvec` from `N` arbitrary bytes, clearing the unused bits beyond the length `L`.
+    /// 
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_raw_bytes(u.arbitrary()?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; N] as Arbitrary<'a>>::size_hint(depth)
+    }
+}
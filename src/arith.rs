@@ -0,0 +1,146 @@
+use crate::Binvec;
+
+
+// impl arithmetic
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Adds two [`Binvec`]s as `L` bit unsigned integers and reports whether the sum overflowed.
+    ///
+    /// The bit at index `0` is the least significant bit, the same as [`to_u64`](Binvec::to_u64).
+    /// The carry ripples through the bytes from index `0` upward.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to add.
+    ///
+    /// ---
+    /// # Returns
+    /// A tuple of the sum modulo `2^L` and the carry out of the bit at index `L - 1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0xFF, 0x0F]); // 4095
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0x02, 0x00]); // 2
+    /// let (sum, carry) = a.overflowing_add(&b);
+    /// assert_eq!(sum.to_u64(), Some(1));
+    /// assert_eq!(carry, true);
+    /// ```
+    /// 
+    pub const fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let mut sum: Binvec<L, N> = *self;
+        let mut carry: u16 = 0;
+        let mut i: usize = 0;
+        while i < N {
+            let total: u16 = self.inner[i] as u16 + other.inner[i] as u16 + carry;
+            sum.inner[i] = total as u8;
+            carry = total >> 8;
+            i += 1;
+        }
+        let overflow: bool = if L.is_multiple_of(8) {
+            carry != 0
+        } else {
+            (sum.inner[N - 1] >> (L % 8)) & 1 != 0 // the carry out lands in the first unused bit
+        };
+        sum.clear_unused_bits();
+        (sum, overflow)
+    }
+
+    /// Adds two [`Binvec`]s as `L` bit unsigned integers, wrapping around on overflow.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to add.
+    ///
+    /// ---
+    /// # Returns
+    /// The sum modulo `2^L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0xFF, 0x0F]); // 4095
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0x03, 0x00]); // 3
+    /// assert_eq!(a.wrapping_add(&b).to_u64(), Some(2));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Adds `1` to the [`Binvec`] as an `L` bit unsigned integer, wrapping around to `0` after all ones.
+    ///
+    /// Only the trailing ones and the first `0` above them change, which are found with [`first_zero`](Binvec::first_zero).
+    ///
+    /// ---
+    /// # Returns
+    /// - `true` if every bit was `1` and the [`Binvec`] wrapped around to all `0`.
+    /// - `false` otherwise.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut counter = binvec!(2, false);
+    /// assert_eq!(counter.increment(), false);
+    /// assert_eq!(counter.to_u64(), Some(1));
+    /// counter.increment();
+    /// counter.increment();
+    /// assert_eq!(counter.increment(), true);
+    /// assert!(counter.is_all_zero());
+    /// ```
+    /// 
+    pub const fn increment(&mut self) -> bool {
+        match self.first_zero() {
+            Some(index) => {
+                let _ = self.fill_range(0..index, false); // the range is within bounds
+                self.inner[index >> 3] |= 1 << (index & 0b111);
+                false
+            }
+            None => {
+                self.fill(false);
+                true
+            }
+        }
+    }
+
+    /// Subtracts `1` from the [`Binvec`] as an `L` bit unsigned integer, wrapping around to all ones after `0`.
+    ///
+    /// Only the trailing zeros and the first `1` above them change, which are found with [`first_one`](Binvec::first_one).
+    ///
+    /// ---
+    /// # Returns
+    /// - `true` if every bit was `0` and the [`Binvec`] wrapped around to all `1`.
+    /// - `false` otherwise.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut counter = binvec!(2, false);
+    /// assert_eq!(counter.decrement(), true);
+    /// assert_eq!(counter.to_u64(), Some(3));
+    /// assert_eq!(counter.decrement(), false);
+    /// assert_eq!(counter.to_u64(), Some(2));
+    /// ```
+    /// 
+    pub const fn decrement(&mut self) -> bool {
+        match self.first_one() {
+            Some(index) => {
+                let _ = self.fill_range(0..index, true); // the range is within bounds
+                self.inner[index >> 3] &= !(1 << (index & 0b111));
+                false
+            }
+            None => {
+                self.fill(true);
+                true
+            }
+        }
+    }
+}
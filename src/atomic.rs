@@ -0,0 +1,404 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+use crate::{Binvec, error};
+
+
+/// A fixed-length bit array that can be shared and updated between threads without a lock.
+///
+/// The bits are packed into [`AtomicU8`]s in the same order as [`Binvec`],
+/// so every single-bit operation is one atomic read-modify-write on the byte that holds the bit.
+/// Operations on different bits never interfere, even when they share a byte.
+///
+/// ---
+/// # Generics
+/// - `L`: The number of bits to store.
+/// - `N`: The minimum byte array length required to store `L`.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+/// use std::sync::atomic::Ordering;
+///
+/// let slots = AtomicBinvec::<12, 2>::new();
+/// assert_eq!(slots.fetch_set(3, Ordering::AcqRel), Ok(false));
+/// assert_eq!(slots.fetch_set(3, Ordering::AcqRel), Ok(true));
+/// assert_eq!(slots.load(3, Ordering::Acquire), Some(true));
+/// ```
+/// 
+#[derive(Debug)]
+pub struct AtomicBinvec<const L: usize, const N: usize> {
+    inner: [AtomicU8; N],
+}
+
+
+impl<const L: usize, const N: usize> AtomicBinvec<L, N> {
+    /// Creates a new `AtomicBinvec` with every bit set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length `AtomicBinvec` with every bit cleared.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// static FREE: AtomicBinvec<64, 8> = AtomicBinvec::new();
+    /// assert_eq!(FREE.len(), 64);
+    /// ```
+    /// 
+    pub const fn new() -> Self {
+        const { assert!(N == L.div_ceil(8), "N must be (L + 7) / 8, the number of bytes needed to store L bits") };
+        Self { inner: [const { AtomicU8::new(0x00) }; N] }
+    }
+
+    /// Creates a new `AtomicBinvec` holding the bits of a [`Binvec`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `binvec`: The initial bits.
+    ///
+    /// ---
+    /// # Returns
+    /// An `AtomicBinvec` with the same bits as `binvec`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::from_binvec(binvec!(12, true));
+    /// assert_eq!(atomic.load(11, Ordering::Relaxed), Some(true));
+    /// ```
+    /// 
+    pub const fn from_binvec(binvec: Binvec<L, N>) -> Self {
+        let bytes: [u8; N] = binvec.into_inner();
+        let mut atomic: AtomicBinvec<L, N> = Self::new();
+        let mut i: usize = 0;
+        while i < N {
+            atomic.inner[i] = AtomicU8::new(bytes[i]);
+            i += 1;
+        }
+        atomic
+    }
+
+    /// Consumes the `AtomicBinvec` and returns its bits as a [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] with the same bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let atomic = AtomicBinvec::from_binvec(binvec!(12, true));
+    /// assert_eq!(atomic.into_binvec().count_ones(), 12);
+    /// ```
+    /// 
+    pub fn into_binvec(self) -> Binvec<L, N> {
+        Binvec::from_raw_bytes(self.inner.map(AtomicU8::into_inner))
+    }
+
+    /// Reads every bit into a [`Binvec`].
+    ///
+    /// Each byte is loaded atomically on its own, so the result is not a consistent snapshot
+    /// if other threads modify bits in several bytes at the same time.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `ordering`: The memory ordering of each byte load.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the loaded bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::<12, 2>::new();
+    /// atomic.store(4, true, Ordering::Relaxed).unwrap();
+    /// assert_eq!(atomic.load_all(Ordering::Relaxed).first_one(), Some(4));
+    /// ```
+    /// 
+    pub fn load_all(&self, ordering: Ordering) -> Binvec<L, N> {
+        Binvec::from_raw_bytes(core::array::from_fn(|i| self.inner[i].load(ordering)))
+    }
+
+    /// Returns the length in bits of the `AtomicBinvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of bits stored.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(AtomicBinvec::<12, 2>::new().len(), 12);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        L
+    }
+
+    /// Returns `true` if the `AtomicBinvec` stores no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length `L` is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(AtomicBinvec::<12, 2>::new().is_empty(), false);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        L == 0
+    }
+
+    /// Atomically reads the bit at the given index.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to read.
+    /// - `ordering`: The memory ordering of the load.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(bit)` at `index`.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::<12, 2>::new();
+    /// assert_eq!(atomic.load(3, Ordering::Relaxed), Some(false));
+    /// assert_eq!(atomic.load(12, Ordering::Relaxed), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn load(&self, index: usize, ordering: Ordering) -> Option<bool> {
+        if index < L {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(((self.inner[byte_index].load(ordering) >> bit_offset) & 1) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Atomically sets the bit at the given index to `value`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to write.
+    /// - `value`: The bit value to store (`true` for 1, `false` for 0).
+    /// - `ordering`: The memory ordering of the read-modify-write.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was stored.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::<12, 2>::new();
+    /// atomic.store(3, true, Ordering::Release).unwrap();
+    /// assert_eq!(atomic.load(3, Ordering::Acquire), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn store(&self, index: usize, value: bool, ordering: Ordering) -> Result<(), error::IndexOutOfBounds> {
+        if value {
+            self.fetch_set(index, ordering).map(|_| ())
+        } else {
+            self.fetch_clear(index, ordering).map(|_| ())
+        }
+    }
+
+    /// Atomically sets the bit at the given index to `1` and returns its previous value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to set.
+    /// - `ordering`: The memory ordering of the read-modify-write.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(previous)` value of the bit.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::<12, 2>::new();
+    /// assert_eq!(atomic.fetch_set(3, Ordering::AcqRel), Ok(false));
+    /// assert_eq!(atomic.fetch_set(3, Ordering::AcqRel), Ok(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn fetch_set(&self, index: usize, ordering: Ordering) -> Result<bool, error::IndexOutOfBounds> {
+        if index < L {
+            let mask: u8 = 1 << (index & 0b111);
+            Ok(self.inner[index >> 3].fetch_or(mask, ordering) & mask != 0)
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Atomically clears the bit at the given index to `0` and returns its previous value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to clear.
+    /// - `ordering`: The memory ordering of the read-modify-write.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(previous)` value of the bit.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::from_binvec(binvec!(12, true));
+    /// assert_eq!(atomic.fetch_clear(3, Ordering::AcqRel), Ok(true));
+    /// assert_eq!(atomic.fetch_clear(3, Ordering::AcqRel), Ok(false));
+    /// ```
+    /// 
+    #[inline]
+    pub fn fetch_clear(&self, index: usize, ordering: Ordering) -> Result<bool, error::IndexOutOfBounds> {
+        if index < L {
+            let mask: u8 = 1 << (index & 0b111);
+            Ok(self.inner[index >> 3].fetch_and(!mask, ordering) & mask != 0)
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Atomically flips the bit at the given index and returns its previous value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to flip.
+    /// - `ordering`: The memory ordering of the read-modify-write.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(previous)` value of the bit.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let atomic = AtomicBinvec::<12, 2>::new();
+    /// assert_eq!(atomic.fetch_toggle(3, Ordering::AcqRel), Ok(false));
+    /// assert_eq!(atomic.load(3, Ordering::Acquire), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn fetch_toggle(&self, index: usize, ordering: Ordering) -> Result<bool, error::IndexOutOfBounds> {
+        if index < L {
+            let mask: u8 = 1 << (index & 0b111);
+            Ok(self.inner[index >> 3].fetch_xor(mask, ordering) & mask != 0)
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Atomically finds the first bit set to `0`, sets it to `1`, and returns its index.
+    ///
+    /// The bytes are scanned in order, and a bit is claimed with a compare-exchange loop on its byte,
+    /// so two threads never claim the same bit. This is the usual primitive for allocating a free slot.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `ordering`: The memory ordering of a successful claim. A failed attempt uses the matching load ordering.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the bit that was claimed.
+    /// - `None` if every bit was `1` when its byte was checked.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let slots = AtomicBinvec::<3, 1>::new();
+    /// slots.store(0, true, Ordering::Relaxed).unwrap();
+    /// assert_eq!(slots.find_and_set_first_zero(Ordering::AcqRel), Some(1));
+    /// assert_eq!(slots.find_and_set_first_zero(Ordering::AcqRel), Some(2));
+    /// assert_eq!(slots.find_and_set_first_zero(Ordering::AcqRel), None);
+    /// ```
+    /// 
+    pub fn find_and_set_first_zero(&self, ordering: Ordering) -> Option<usize> {
+        let failure: Ordering = match ordering {
+            Ordering::SeqCst => Ordering::SeqCst,
+            Ordering::Acquire | Ordering::AcqRel => Ordering::Acquire,
+            _ => Ordering::Relaxed,
+        };
+        for (i, byte) in self.inner.iter().enumerate() {
+            let mut current: u8 = byte.load(failure);
+            while current != 0xFF {
+                let bit_offset: usize = current.trailing_ones() as usize;
+                let index: usize = (i << 3) + bit_offset;
+                if index >= L {
+                    break; // only unused bits are left in the last byte
+                }
+                match byte.compare_exchange_weak(current, current | (1 << bit_offset), ordering, failure) {
+                    Ok(_) => return Some(index),
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        None
+    }
+}
+
+
+// impl Default
+impl<const L: usize, const N: usize> Default for AtomicBinvec<L, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+// impl From
+impl<const L: usize, const N: usize> From<Binvec<L, N>> for AtomicBinvec<L, N> {
+    #[inline]
+    fn from(binvec: Binvec<L, N>) -> Self {
+        Self::from_binvec(binvec)
+    }
+}
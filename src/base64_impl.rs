@@ -0,0 +1,60 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use crate::{error, Binvec};
+
+
+// impl base64
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Encodes the packed bytes of the [`Binvec`] as a standard base64 string with padding.
+    ///
+    /// ---
+    /// # Returns
+    /// The base64 encoding of the `N` packed bytes.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.to_base64(), "/w8=");
+    /// ```
+    /// 
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.inner)
+    }
+
+    /// Decodes a standard base64 string produced by [`to_base64`](Binvec::to_base64).
+    ///
+    /// ---
+    /// # Arguments
+    /// - `s`: The base64 encoding of the `N` packed bytes.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(Binvec)` holding the decoded bits.
+    /// - `Err(DecodeBase64Error)` if the string is not valid base64, does not decode to exactly `N` bytes,
+    ///   or sets the unused bits beyond the length `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<12, 2>::from_base64("/w8=").unwrap();
+    /// assert!(binvec.is_all_one());
+    ///
+    /// assert_eq!(
+    ///     Binvec::<12, 2>::from_base64("/w=="),
+    ///     Err(error::DecodeBase64Error::LengthMismatch { expected: 2, found: 1 }),
+    /// );
+    /// ```
+    /// 
+    pub fn from_base64(s: &str) -> Result<Self, error::DecodeBase64Error> {
+        let decoded: Vec<u8> = STANDARD.decode(s).map_err(|_| error::DecodeBase64Error::InvalidBase64)?;
+        let bytes: [u8; N] = decoded.as_slice().try_into().map_err(|_| {
+            error::DecodeBase64Error::LengthMismatch { expected: N, found: decoded.len() }
+        })?;
+        Self::from_bytes(bytes).map_err(|_| error::DecodeBase64Error::NonZeroPadding)
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{BinvecSlice, BinvecSliceMut, error, kernel};
+
+
+/// A fixed-size two-dimensional bit matrix stored row by row.
+///
+/// The bit at row `r` and column `c` is the bit at index `r * C + c` of the packed bytes,
+/// laid out the same way as a [`Binvec`](crate::Binvec) of length `R * C`,
+/// so rows are not padded and each row can be viewed as a [`BinvecSlice`].
+///
+/// ---
+/// # Generics
+/// - `R`: The number of rows.
+/// - `C`: The number of columns.
+/// - `N`: The minimum byte array length required to store `R * C` bits.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut grid = Binmat::<3, 5, 2>::new();
+/// grid.set(1, 4, true).unwrap();
+/// assert_eq!(grid.get(1, 4), Some(true));
+/// assert_eq!(grid.row_count_ones(1), Some(1));
+/// ```
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Binmat<const R: usize, const C: usize, const N: usize> {
+    inner: [u8; N],
+}
+
+
+impl<const R: usize, const C: usize, const N: usize> Binmat<R, C, N> {
+    /// Creates a new `Binmat` with every bit set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// An `R` by `C` `Binmat` with every bit cleared.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let grid = Binmat::<3, 5, 2>::new();
+    /// assert_eq!(grid.count_ones(), 0);
+    /// ```
+    /// 
+    pub const fn new() -> Self {
+        const { assert!(N == (R * C).div_ceil(8), "N must be the number of bytes needed for R * C bits") };
+        Self { inner: [0x00; N] }
+    }
+
+    /// Returns the number of rows of the `Binmat`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of rows `R`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(Binmat::<3, 5, 2>::new().rows(), 3);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    /// Returns the number of columns of the `Binmat`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of columns `C`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(Binmat::<3, 5, 2>::new().cols(), 5);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn cols(&self) -> usize {
+        C
+    }
+
+    /// Returns the bit at the given row and column.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `row`: The row index.
+    /// - `col`: The column index.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(bit)` at `row` and `col`.
+    /// - `None` if `row` or `col` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let grid = Binmat::<3, 5, 2>::new();
+    /// assert_eq!(grid.get(2, 4), Some(false));
+    /// assert_eq!(grid.get(3, 0), None);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row < R
+        && col < C {
+            let index: usize = row * C + col;
+            Some((self.inner[index >> 3] >> (index & 0b111)) & 1 != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit at the given row and column to `value`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `row`: The row index.
+    /// - `col`: The column index.
+    /// - `value`: The bit value to store (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was stored.
+    /// - `Err(IndexOutOfBounds)` if `row` or `col` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(2, 4, true).unwrap();
+    /// assert_eq!(grid.get(2, 4), Some(true));
+    /// assert!(grid.set(0, 5, true).is_err());
+    /// ```
+    /// 
+    #[inline]
+    pub const fn set(&mut self, row: usize, col: usize, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        if row < R
+        && col < C {
+            let index: usize = row * C + col;
+            let mask: u8 = 1 << (index & 0b111);
+            if value {
+                self.inner[index >> 3] |= mask;
+            } else {
+                self.inner[index >> 3] &= !mask;
+            }
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Returns a [`BinvecSlice`] view of a row.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `row`: The row index.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(slice)` of the `C` bits of `row`, where index `c` is column `c`.
+    /// - `None` if `row` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(1, 2, true).unwrap();
+    /// let row = grid.row(1).unwrap();
+    /// assert_eq!(row.len(), 5);
+    /// assert_eq!(row.get(2), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn row(&self, row: usize) -> Option<BinvecSlice<'_>> {
+        if row < R {
+            Some(BinvecSlice::new(&self.inner, row * C, C))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a [`BinvecSliceMut`] view of a row.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `row`: The row index.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(slice)` of the `C` bits of `row`, where index `c` is column `c`.
+    /// - `None` if `row` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.row_mut(1).unwrap().fill(true);
+    /// assert_eq!(grid.row_count_ones(1), Some(5));
+    /// assert_eq!(grid.count_ones(), 5);
+    /// ```
+    /// 
+    #[inline]
+    pub fn row_mut(&mut self, row: usize) -> Option<BinvecSliceMut<'_>> {
+        if row < R {
+            Some(BinvecSliceMut::new(&mut self.inner[..], row * C, C))
+        } else {
+            None
+        }
+    }
+
+    /// Counts the bits set to `1` in a row.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `row`: The row index.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(count)` of the bits that are `1` in `row`.
+    /// - `None` if `row` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(2, 0, true).unwrap();
+    /// grid.set(2, 3, true).unwrap();
+    /// assert_eq!(grid.row_count_ones(2), Some(2));
+    /// assert_eq!(grid.row_count_ones(3), None);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn row_count_ones(&self, row: usize) -> Option<usize> {
+        match self.row(row) {
+            Some(slice) => Some(slice.count_ones()),
+            None => None,
+        }
+    }
+
+    /// Counts the bits set to `1` in the whole `Binmat`.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(0, 0, true).unwrap();
+    /// grid.set(2, 4, true).unwrap();
+    /// assert_eq!(grid.count_ones(), 2);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn count_ones(&self) -> usize {
+        kernel::count_ones(&self.inner) // unused bits are always 0
+    }
+
+    /// Returns the transpose of the `Binmat`, where row `r` and column `c` become row `c` and column `r`.
+    ///
+    /// When both `R` and `C` are multiples of 8, every row starts on a byte boundary,
+    /// so the matrix is transposed in 8 by 8 blocks with a few shifts and masks on a [`u64`] per block.
+    /// Otherwise the bits are moved one at a time.
+    ///
+    /// ---
+    /// # Returns
+    /// A `C` by `R` `Binmat` holding the transposed bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(1, 4, true).unwrap();
+    /// let transposed: Binmat<5, 3, 2> = grid.transpose();
+    /// assert_eq!(transposed.get(4, 1), Some(true));
+    /// assert_eq!(transposed.count_ones(), 1);
+    /// ```
+    /// 
+    pub const fn transpose(&self) -> Binmat<C, R, N> {
+        let mut transposed: Binmat<C, R, N> = Binmat::new();
+        if R.is_multiple_of(8)
+        && C.is_multiple_of(8) {
+            let row_bytes: usize = C >> 3; // same as `C / 8`
+            let col_bytes: usize = R >> 3; // same as `R / 8`
+            let mut block_row: usize = 0;
+            while block_row < col_bytes {
+                let mut block_col: usize = 0;
+                while block_col < row_bytes {
+                    let mut block: [u8; 8] = [0x00; 8];
+                    let mut i: usize = 0;
+                    while i < 8 {
+                        block[i] = self.inner[((block_row << 3) + i) * row_bytes + block_col];
+                        i += 1;
+                    }
+                    block = transpose_8x8(u64::from_le_bytes(block)).to_le_bytes();
+                    let mut i: usize = 0;
+                    while i < 8 {
+                        transposed.inner[((block_col << 3) + i) * col_bytes + block_row] = block[i];
+                        i += 1;
+                    }
+                    block_col += 1;
+                }
+                block_row += 1;
+            }
+        } else {
+            let mut row: usize = 0;
+            while row < R {
+                let mut col: usize = 0;
+                while col < C {
+                    let from: usize = row * C + col;
+                    if (self.inner[from >> 3] >> (from & 0b111)) & 1 != 0 {
+                        let to: usize = col * R + row;
+                        transposed.inner[to >> 3] |= 1 << (to & 0b111);
+                    }
+                    col += 1;
+                }
+                row += 1;
+            }
+        }
+        transposed
+    }
+
+    /// Returns a reference to the packed byte representation of the `Binmat`.
+    ///
+    /// ---
+    /// # Returns
+    /// The backing byte array, where bit `r * C + c` is bit `(r * C + c) % 8` of byte `(r * C + c) / 8`.
+    /// The unused bits beyond `R * C` are always `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut grid = Binmat::<3, 5, 2>::new();
+    /// grid.set(1, 0, true).unwrap();
+    /// assert_eq!(grid.as_bytes(), &[0b0010_0000, 0x00]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.inner
+    }
+}
+
+
+/// Transposes an 8 by 8 bit block where bit `8 * i + j` is row `i` and column `j`.
+///
+/// Each step swaps the off-diagonal halves of 2 by 2, then 4 by 4, then 8 by 8 sub-blocks.
+const fn transpose_8x8(mut x: u64) -> u64 {
+    let mut t: u64 = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+    x ^= t ^ (t << 7);
+    t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+    x ^= t ^ (t << 14);
+    t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+    x ^= t ^ (t << 28);
+    x
+}
+
+
+// impl Default
+impl<const R: usize, const C: usize, const N: usize> Default for Binmat<R, C, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,1023 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range};
+use crate::{Binvec, BinvecSlice, BinvecSliceIter, BinvecSliceMut, error, kernel};
+
+
+/// A heap-allocated bit vector whose length is only known at runtime.
+///
+/// `BinvecDyn` is the growable counterpart of [`Binvec`]: the bits are packed into a [`Vec<u8>`]
+/// in the same order, so bit `i` is bit `i % 8` of byte `i / 8`,
+/// and the unused bits in the last byte are always `0`.
+/// It has the single-bit accessors, counting, searching, range fills, and the `&`, `|`, `^`, and `!` operators of a [`Binvec`],
+/// and can be read through [`BinvecSlice`] and modified through [`BinvecSliceMut`].
+/// The rest of the [`Binvec`] API, such as shifts, rotations, and conversions, is not available.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut flags = BinvecDyn::new();
+/// flags.push(true);
+/// flags.push(false);
+/// flags.resize(10, true);
+/// assert_eq!(flags.len(), 10);
+/// assert_eq!(flags.count_ones(), 9);
+///
+/// let mask = BinvecDyn::from_elem(10, true);
+/// assert_eq!((&flags ^ &mask).iter_ones().collect::<Vec<_>>(), [1]);
+/// ```
+/// 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BinvecDyn {
+    inner: Vec<u8>,
+    len: usize,
+}
+
+
+impl BinvecDyn {
+    /// Creates a new, empty `BinvecDyn` without allocating.
+    ///
+    /// ---
+    /// # Returns
+    /// A `BinvecDyn` of length `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::new();
+    /// assert!(binvec.is_empty());
+    /// ```
+    /// 
+    #[inline]
+    pub const fn new() -> Self {
+        Self { inner: Vec::new(), len: 0 }
+    }
+
+    /// Creates a new, empty `BinvecDyn` with room for at least `capacity` bits.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `capacity`: The number of bits that can be pushed without reallocating.
+    ///
+    /// ---
+    /// # Returns
+    /// A `BinvecDyn` of length `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::with_capacity(100);
+    /// assert!(binvec.capacity() >= 100);
+    /// ```
+    /// 
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { inner: Vec::with_capacity(capacity.div_ceil(8)), len: 0 }
+    }
+
+    /// Creates a new `BinvecDyn` of the given length with every bit set to `value`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `len`: The number of bits to store.
+    /// - `value`: The initial value of every bit.
+    ///
+    /// ---
+    /// # Returns
+    /// A `len` length `BinvecDyn` initialized with `value`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(12, true);
+    /// assert_eq!(binvec.count_ones(), 12);
+    /// ```
+    /// 
+    pub fn from_elem(len: usize, value: bool) -> Self {
+        let byte: u8 = if value { 0xFF } else { 0x00 };
+        let mut binvec: BinvecDyn = Self { inner: alloc::vec![byte; len.div_ceil(8)], len };
+        binvec.clear_unused_bits();
+        binvec
+    }
+
+    /// Returns the length in bits of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of bits stored.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(12, false);
+    /// assert_eq!(binvec.len(), 12);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `BinvecDyn` stores no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(BinvecDyn::new().is_empty(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bits the `BinvecDyn` can hold without reallocating.
+    ///
+    /// ---
+    /// # Returns
+    /// The capacity in bits, which is always a multiple of 8.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::with_capacity(12);
+    /// assert!(binvec.capacity() >= 16);
+    /// ```
+    /// 
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity() << 3
+    }
+
+    /// Returns the bit value at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to retrieve.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(true)` if the bit at `index` is 1.
+    /// - `Some(false)` if the bit at `index` is 0.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(12, true);
+    /// assert_eq!(binvec.get(5), Some(true));
+    /// assert_eq!(binvec.get(20), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(((self.inner[byte_index] >> bit_offset) & 1) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit value at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to set.
+    /// - `value`: The bit value to set (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was set.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.get(3), Some(true));
+    /// assert_eq!(binvec.set(20, true), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        if index < self.len {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            if value {
+                self.inner[byte_index] |= 1 << bit_offset;
+            } else {
+                self.inner[byte_index] &= !(1 << bit_offset);
+            }
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Flips the bit at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to flip.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was flipped.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.toggle(3).unwrap();
+    /// assert_eq!(binvec.get(3), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn toggle(&mut self, index: usize) -> Result<(), error::IndexOutOfBounds> {
+        if index < self.len {
+            self.inner[index >> 3] ^= 1 << (index & 0b111);
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Sets the bit at the given index and returns its previous value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to write.
+    /// - `value`: The new bit value (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(bool)` with the value the bit had before.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// assert_eq!(binvec.replace(3, true), Ok(false));
+    /// assert_eq!(binvec.replace(3, false), Ok(true));
+    /// assert_eq!(binvec.replace(20, true), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    #[inline]
+    pub fn replace(&mut self, index: usize, value: bool) -> Result<bool, error::IndexOutOfBounds> {
+        let old: bool = self.get(index).ok_or(error::IndexOutOfBounds)?;
+        self.set(index, value)?;
+        Ok(old)
+    }
+
+    /// Appends a bit to the end of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to append.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::new();
+    /// binvec.push(true);
+    /// binvec.push(false);
+    /// assert_eq!(binvec.len(), 2);
+    /// assert_eq!(binvec.get(0), Some(true));
+    /// ```
+    /// 
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(8) {
+            self.inner.push(0x00);
+        }
+        self.len += 1;
+        if value {
+            self.inner[(self.len - 1) >> 3] |= 1 << ((self.len - 1) & 0b111);
+        }
+    }
+
+    /// Removes the last bit and returns it.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(bit)` that was removed from the end.
+    /// - `None` if the `BinvecDyn` is empty.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::new();
+    /// binvec.push(true);
+    /// assert_eq!(binvec.pop(), Some(true));
+    /// assert_eq!(binvec.pop(), None);
+    /// ```
+    /// 
+    pub fn pop(&mut self) -> Option<bool> {
+        let bit: bool = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(bit)
+    }
+
+    /// Resizes the `BinvecDyn` to the given length.
+    ///
+    /// New bits are set to `value`. When shrinking, this is the same as [`truncate`](BinvecDyn::truncate).
+    ///
+    /// ---
+    /// # Arguments
+    /// - `new_len`: The new length in bits.
+    /// - `value`: The value of the bits added when growing.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(3, false);
+    /// binvec.resize(12, true);
+    /// assert_eq!(binvec.count_ones(), 9);
+    /// binvec.resize(5, true);
+    /// assert_eq!(binvec.count_ones(), 2);
+    /// ```
+    /// 
+    pub fn resize(&mut self, new_len: usize, value: bool) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        let old_len: usize = self.len;
+        if value
+        && !old_len.is_multiple_of(8) {
+            self.inner[old_len >> 3] |= 0xFF << (old_len & 0b111); // fill the rest of the last byte
+        }
+        self.inner.resize(new_len.div_ceil(8), if value { 0xFF } else { 0x00 });
+        self.len = new_len;
+        self.clear_unused_bits();
+    }
+
+    /// Shortens the `BinvecDyn` to the given length, dropping the bits beyond it.
+    ///
+    /// This has no effect if `new_len` is not less than the current length.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `new_len`: The new length in bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, true);
+    /// binvec.truncate(5);
+    /// assert_eq!(binvec.len(), 5);
+    /// assert_eq!(binvec.count_ones(), 5);
+    /// ```
+    /// 
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            self.inner.truncate(new_len.div_ceil(8));
+            self.len = new_len;
+            self.clear_unused_bits();
+        }
+    }
+
+    /// Removes every bit, keeping the allocated capacity.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, true);
+    /// binvec.clear();
+    /// assert!(binvec.is_empty());
+    /// ```
+    /// 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.len = 0;
+    }
+
+    /// Fills the entire `BinvecDyn` with the specified bit value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to fill with (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.fill(true);
+    /// assert_eq!(binvec.count_ones(), 12);
+    /// ```
+    /// 
+    pub fn fill(&mut self, value: bool) {
+        self.inner.fill(if value { 0xFF } else { 0x00 });
+        self.clear_unused_bits();
+    }
+
+    /// Fills every bit in the given range with the specified bit value.
+    ///
+    /// The partial first and last bytes are masked, and the bytes in between are written whole.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to fill.
+    /// - `value`: The bit value to fill the range with (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was filled.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(20, false);
+    /// binvec.fill_range(3..17, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 14);
+    /// assert_eq!(binvec.fill_range(3..21, true), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    pub fn fill_range(&mut self, range: Range<usize>, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        if range.start > range.end
+        || range.end > self.len {
+            return Err(error::IndexOutOfBounds);
+        }
+        BinvecSliceMut::new(&mut self.inner[..], range.start, range.end - range.start).fill(value);
+        Ok(())
+    }
+
+    /// Sets every bit in the given range to `1`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to set.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was set.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set_range(2..5).unwrap();
+    /// assert_eq!(binvec.count_ones(), 3);
+    /// ```
+    /// 
+    #[inline]
+    pub fn set_range(&mut self, range: Range<usize>) -> Result<(), error::IndexOutOfBounds> {
+        self.fill_range(range, true)
+    }
+
+    /// Clears every bit in the given range to `0`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to clear.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was cleared.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, true);
+    /// binvec.clear_range(2..5).unwrap();
+    /// assert_eq!(binvec.count_ones(), 9);
+    /// ```
+    /// 
+    #[inline]
+    pub fn clear_range(&mut self, range: Range<usize>) -> Result<(), error::IndexOutOfBounds> {
+        self.fill_range(range, false)
+    }
+
+    /// Inverts every bit of the `BinvecDyn` in place.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// binvec.flip_all();
+    /// assert_eq!(binvec.count_ones(), 11);
+    /// ```
+    /// 
+    pub fn flip_all(&mut self) {
+        for byte in self.inner.iter_mut() {
+            *byte = !*byte;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Counts the number of bits set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub fn count_ones(&self) -> usize {
+        kernel::count_ones_simd(&self.inner) // unused bits are always 0
+    }
+
+    /// Counts the number of bits set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.count_zeros(), 11);
+    /// ```
+    /// 
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Returns the index of the first bit set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the lowest bit that is `1`.
+    /// - `None` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// assert_eq!(binvec.first_one(), None);
+    /// binvec.set(9, true).unwrap();
+    /// assert_eq!(binvec.first_one(), Some(9));
+    /// ```
+    /// 
+    #[inline]
+    pub fn first_one(&self) -> Option<usize> {
+        kernel::first_one(&self.inner, false) // unused bits are always 0
+    }
+
+    /// Returns the index of the first bit set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the lowest bit that is `0`.
+    /// - `None` if every bit is `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, true);
+    /// assert_eq!(binvec.first_zero(), None);
+    /// binvec.set(9, false).unwrap();
+    /// assert_eq!(binvec.first_zero(), Some(9));
+    /// ```
+    /// 
+    #[inline]
+    pub fn first_zero(&self) -> Option<usize> {
+        kernel::first_one(&self.inner, true).filter(|&index| index < self.len) // unused bits read as `0`
+    }
+
+    /// Returns the index of the last bit set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the highest bit that is `1`.
+    /// - `None` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(9, true).unwrap();
+    /// assert_eq!(binvec.last_one(), Some(9));
+    /// ```
+    /// 
+    #[inline]
+    pub fn last_one(&self) -> Option<usize> {
+        kernel::last_one(&self.inner) // unused bits are always 0
+    }
+
+    /// Counts the bits set to `1` strictly before the given index.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The exclusive end of the counted range `[0, index)`. The length itself is allowed.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(count)` of the bits that are `1` in `[0, index)`.
+    /// - `None` if `index` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// assert_eq!(binvec.rank(3), Some(1));
+    /// assert_eq!(binvec.rank(12), Some(2));
+    /// assert_eq!(binvec.rank(13), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        if index > self.len {
+            return None;
+        }
+        Some(kernel::rank(&self.inner, index))
+    }
+
+    /// Returns the index of the `n`-th bit set to `1`, counting from `0`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The zero-based rank of the `1` bit to find.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the `n`-th bit that is `1`.
+    /// - `None` if there are `n` or fewer bits set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// assert_eq!(binvec.select(1), Some(10));
+    /// assert_eq!(binvec.select(2), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn select(&self, n: usize) -> Option<usize> {
+        kernel::select(&self.inner, n) // unused bits are always 0
+    }
+
+    /// Returns the packed bytes of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Returns
+    /// The backing bytes. The unused bits beyond the length in the last byte are always `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(12, true);
+    /// assert_eq!(binvec.as_bytes(), &[0xFF, 0x0F]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Returns a [`BinvecSlice`] view of every bit of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSlice`] of the same length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(12, true);
+    /// assert_eq!(binvec.as_slice().slice(2..6).unwrap().count_ones(), 4);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_slice(&self) -> BinvecSlice<'_> {
+        BinvecSlice::new(&self.inner, 0, self.len)
+    }
+
+    /// Returns a [`BinvecSliceMut`] view of every bit of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceMut`] of the same length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(12, false);
+    /// let (mut left, _) = binvec.as_mut_slice().split_at_mut(4).unwrap();
+    /// left.fill(true);
+    /// assert_eq!(binvec.count_ones(), 4);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> BinvecSliceMut<'_> {
+        BinvecSliceMut::new(&mut self.inner[..], 0, self.len)
+    }
+
+    /// Returns an iterator over the bits of the `BinvecDyn`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceIter`] that yields each bit as a `bool`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = BinvecDyn::from_elem(3, true);
+    /// assert_eq!(binvec.iter().collect::<Vec<_>>(), [true, true, true]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn iter(&self) -> BinvecSliceIter<'_> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over the indices of the bits set to `1`, in ascending order.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecDynIterOnes`] that skips runs of `0` bits a word at a time.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = BinvecDyn::from_elem(100, false);
+    /// binvec.set(3, true).unwrap();
+    /// binvec.set(70, true).unwrap();
+    /// assert_eq!(binvec.iter_ones().collect::<Vec<_>>(), [3, 70]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn iter_ones(&self) -> BinvecDynIterOnes<'_> {
+        BinvecDynIterOnes { bytes: &self.inner, word: 0, word_start: 0, next_byte: 0 }
+    }
+
+    /// Clears the unused bits beyond the length in the last byte.
+    /// 
+    #[inline(always)]
+    fn clear_unused_bits(&mut self) {
+        if !self.len.is_multiple_of(8) {
+            let mask: u8 = (1u8 << (self.len % 8)) - 1;
+            self.inner[self.len >> 3] &= mask;
+        }
+    }
+}
+
+
+/// Panics with the lengths of both sides when a bitwise operator gets two `BinvecDyn`s of different lengths.
+#[inline(always)]
+fn assert_same_len(lhs: &BinvecDyn, rhs: &BinvecDyn) {
+    assert!(lhs.len == rhs.len, "length mismatch: the left side has {} bits but the right side has {}", lhs.len, rhs.len);
+}
+
+
+macro_rules! impl_bitwise_dyn {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $kernel:ident;)*) => {$(
+        impl $assign_trait<&BinvecDyn> for BinvecDyn {
+            /// Combines the bits of `rhs` into `self` a word at a time.
+            ///
+            /// ---
+            /// # Panics
+            /// Panics if the two `BinvecDyn`s have different lengths.
+            /// 
+            fn $assign_method(&mut self, rhs: &BinvecDyn) {
+                assert_same_len(self, rhs);
+                kernel::$kernel(&mut self.inner, &rhs.inner); // unused bits stay 0 because both sides are 0
+            }
+        }
+
+
+        impl $assign_trait for BinvecDyn {
+            #[inline]
+            fn $assign_method(&mut self, rhs: BinvecDyn) {
+                self.$assign_method(&rhs);
+            }
+        }
+
+
+        impl $trait<&BinvecDyn> for &BinvecDyn {
+            type Output = BinvecDyn;
+
+            #[inline]
+            fn $method(self, rhs: &BinvecDyn) -> Self::Output {
+                let mut output: BinvecDyn = self.clone();
+                output.$assign_method(rhs);
+                output
+            }
+        }
+
+
+        impl $trait for BinvecDyn {
+            type Output = BinvecDyn;
+
+            #[inline]
+            fn $method(mut self, rhs: BinvecDyn) -> Self::Output {
+                self.$assign_method(&rhs);
+                self
+            }
+        }
+    )*};
+}
+
+// impl BitAnd, BitOr, BitXor and their assignments
+impl_bitwise_dyn! {
+    BitAnd, bitand, BitAndAssign, bitand_assign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign, bitxor_assign;
+}
+
+
+// impl Not
+impl Not for BinvecDyn {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        self.flip_all();
+        self
+    }
+}
+
+
+impl Not for &BinvecDyn {
+    type Output = BinvecDyn;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        !self.clone()
+    }
+}
+
+
+// impl From
+impl<const L: usize, const N: usize> From<&Binvec<L, N>> for BinvecDyn {
+    #[inline]
+    fn from(binvec: &Binvec<L, N>) -> Self {
+        Self { inner: binvec.as_bytes().to_vec(), len: L }
+    }
+}
+
+
+// impl From
+impl<const L: usize, const N: usize> From<Binvec<L, N>> for BinvecDyn {
+    #[inline]
+    fn from(binvec: Binvec<L, N>) -> Self {
+        Self::from(&binvec)
+    }
+}
+
+
+// impl FromIterator
+impl FromIterator<bool> for BinvecDyn {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut binvec: BinvecDyn = Self::new();
+        binvec.extend(iter);
+        binvec
+    }
+}
+
+
+// impl Extend
+impl Extend<bool> for BinvecDyn {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.inner.reserve(iter.size_hint().0.div_ceil(8));
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+
+// impl IntoIterator
+impl<'a> IntoIterator for &'a BinvecDyn {
+    type Item = bool;
+    type IntoIter = BinvecSliceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+/// An iterator over the indices of the bits set to `1` in a `BinvecDyn`.
+///
+/// The iterator loads 64 bits at a time and jumps between set bits with `trailing_zeros`,
+/// so runs of `0` bits are skipped without visiting them one by one.
+/// 
+#[derive(Debug, Clone)]
+pub struct BinvecDynIterOnes<'a> {
+    bytes: &'a [u8],
+    word: u64,
+    word_start: usize,
+    next_byte: usize,
+}
+
+
+// impl Iterator
+impl<'a> Iterator for BinvecDynIterOnes<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            if self.next_byte >= self.bytes.len() {
+                return None;
+            }
+            let end: usize = if self.bytes.len() - self.next_byte < 8 { self.bytes.len() } else { self.next_byte + 8 };
+            let mut word_bytes: [u8; 8] = [0x00; 8];
+            word_bytes[..end - self.next_byte].copy_from_slice(&self.bytes[self.next_byte..end]);
+            self.word = u64::from_le_bytes(word_bytes); // unused bits are always 0
+            self.word_start = self.next_byte << 3;
+            self.next_byte = end;
+        }
+        let offset: usize = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1; // clear the lowest set bit
+        Some(self.word_start + offset)
+    }
+}
+
+
+// impl FusedIterator
+impl<'a> FusedIterator for BinvecDynIterOnes<'a> {}
@@ -0,0 +1,93 @@
+use core::ops::{Deref, DerefMut};
+
+
+/// A mutable reference to a single bit of a `Binvec`.
+///
+/// Packed bits cannot be borrowed as `&mut bool`, so this proxy holds a copy of the bit.
+/// The copy can be read through [`Deref`] and modified through [`DerefMut`],
+/// and it is written back to the `Binvec` when the proxy is dropped.
+/// 
+#[derive(Debug)]
+pub struct BitRefMut<'a> {
+    byte: &'a mut u8,
+    mask: u8,
+    value: bool,
+}
+
+
+impl<'a> BitRefMut<'a> {
+    /// Creates a new `BitRefMut` for the bit at `bit_offset` of `byte`.
+    ///
+    /// `bit_offset` must be less than 8 and must point to a bit in use,
+    /// otherwise writing back would break the unused-bits-are-zero invariant.
+    /// 
+    pub(crate) const fn new(byte: &'a mut u8, bit_offset: usize) -> Self {
+        let mask: u8 = 1 << bit_offset;
+        let value: bool = (*byte & mask) != 0;
+        Self { byte, mask, value }
+    }
+
+    /// Sets the referenced bit and writes it back immediately.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to set (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.get_mut(3).unwrap().set(true);
+    /// assert_eq!(binvec.get(3), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn set(&mut self, value: bool) {
+        self.value = value;
+        self.commit();
+    }
+
+    /// Writes the current value of the proxy back to the `Binvec`.
+    ///
+    /// This also happens automatically when the proxy is dropped.
+    /// 
+    #[inline]
+    pub fn commit(&mut self) {
+        if self.value {
+            *self.byte |= self.mask;
+        } else {
+            *self.byte &= !self.mask;
+        }
+    }
+}
+
+
+// impl Deref
+impl Deref for BitRefMut<'_> {
+    type Target = bool;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+
+// impl DerefMut
+impl DerefMut for BitRefMut<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+
+// impl Drop
+impl Drop for BitRefMut<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.commit();
+    }
+}
@@ -0,0 +1,308 @@
+use core::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use crate::Binvec;
+
+
+/// A fixed-size Bloom filter that stores its bits in a [`Binvec`].
+///
+/// Each item sets `K` bits chosen from one 64-bit hash with double hashing,
+/// so [`contains`](BloomFilter::contains) never gives a false negative but may give a false positive.
+/// The step between the bits is coprime with `L`, so the `K` bits are distinct whenever `K <= L`.
+/// The hash function is taken from `S`, which defaults to [`BloomHasher`] so the filter only needs `core`.
+///
+/// ---
+/// # Generics
+/// - `L`: The number of bits in the filter.
+/// - `N`: The minimum byte array length required to store `L`.
+/// - `K`: The number of bits set per item.
+/// - `S`: The [`BuildHasher`] used to hash the items.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut filter = BloomFilter::<1024, 128, 3>::new();
+/// filter.insert(b"apple");
+/// assert!(filter.contains(b"apple"));
+/// assert!(!filter.contains(b"banana"));
+/// ```
+/// 
+#[derive(Debug, Clone)]
+pub struct BloomFilter<const L: usize, const N: usize, const K: usize, S = BuildHasherDefault<BloomHasher>> {
+    bits: Binvec<L, N>,
+    hasher: S,
+}
+
+
+impl<const L: usize, const N: usize, const K: usize> BloomFilter<L, N, K> {
+    /// Creates a new empty `BloomFilter` using the default [`BloomHasher`].
+    ///
+    /// ---
+    /// # Returns
+    /// A `BloomFilter` with every bit cleared.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let filter = BloomFilter::<1024, 128, 3>::new();
+    /// assert!(filter.is_empty());
+    /// ```
+    /// 
+    pub const fn new() -> Self {
+        Self::with_hasher(BuildHasherDefault::new())
+    }
+}
+
+
+impl<const L: usize, const N: usize, const K: usize, S: BuildHasher> BloomFilter<L, N, K, S> {
+    /// Creates a new empty `BloomFilter` that hashes items with the given [`BuildHasher`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `hasher`: Builds the hasher for each item, for example a keyed hasher to resist crafted inputs.
+    ///
+    /// ---
+    /// # Returns
+    /// A `BloomFilter` with every bit cleared.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3, _>::with_hasher(RandomState::new());
+    /// filter.insert(b"apple");
+    /// assert!(filter.contains(b"apple"));
+    /// ```
+    /// 
+    pub const fn with_hasher(hasher: S) -> Self {
+        const { assert!(K > 0, "K must be at least 1") };
+        const { assert!(L > 0, "L must be at least 1") };
+        Self { bits: Binvec::from_raw_bytes([0x00; N]), hasher }
+    }
+
+    /// Adds an item to the `BloomFilter`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `item`: The bytes of the item to add.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3>::new();
+    /// filter.insert(b"apple");
+    /// assert!(filter.contains(b"apple"));
+    ///
+    /// // every item sets 3 distinct bits, even when L is odd and small
+    /// for item in 0u32..1000 {
+    ///     let mut filter = BloomFilter::<5, 1, 3>::new();
+    ///     filter.insert(&item.to_le_bytes());
+    ///     assert_eq!(filter.as_binvec().count_ones(), 3);
+    /// }
+    /// ```
+    /// 
+    pub fn insert(&mut self, item: &[u8]) {
+        let (mut index, step) = self.probe(item);
+        for _ in 0..K {
+            // SAFETY: index is guaranteed to be less than L
+            unsafe { self.bits.set_unchecked(index, true) };
+            index = (index + step) % L;
+        }
+    }
+
+    /// Checks whether an item may have been added to the `BloomFilter`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `item`: The bytes of the item to look up.
+    ///
+    /// ---
+    /// # Returns
+    /// - `false` if the item was definitely never added.
+    /// - `true` if the item was probably added. Other items can set the same bits, so this may be a false positive.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3>::new();
+    /// filter.insert(b"apple");
+    /// assert_eq!(filter.contains(b"apple"), true);
+    /// assert_eq!(filter.contains(b"cherry"), false);
+    /// ```
+    /// 
+    pub fn contains(&self, item: &[u8]) -> bool {
+        let (mut index, step) = self.probe(item);
+        for _ in 0..K {
+            // SAFETY: index is guaranteed to be less than L
+            if !unsafe { self.bits.get_unchecked(index) } {
+                return false;
+            }
+            index = (index + step) % L;
+        }
+        true
+    }
+
+    /// Returns the first bit index and the step between the `K` bit indices of an item.
+    ///
+    /// The step is nonzero and coprime with `L`, so the first `L` indices it visits are all distinct.
+    fn probe(&self, item: &[u8]) -> (usize, usize) {
+        let mut hasher: S::Hasher = self.hasher.build_hasher();
+        hasher.write(item);
+        let hash: u64 = hasher.finish();
+        let index: usize = (hash % L as u64) as usize;
+        if L == 1 {
+            return (index, 0);
+        }
+        let mut step: usize = 1 + ((hash >> 32) % (L - 1) as u64) as usize; // from 1 to L - 1
+        while gcd(step, L) != 1 {
+            step = if step == L - 1 { 1 } else { step + 1 }; // ends at the latest on 1
+        }
+        (index, step)
+    }
+}
+
+
+/// Returns the greatest common divisor of `a` and `b`.
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let r: usize = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+
+impl<const L: usize, const N: usize, const K: usize, S> BloomFilter<L, N, K, S> {
+    /// Removes every item from the `BloomFilter`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3>::new();
+    /// filter.insert(b"apple");
+    /// filter.clear();
+    /// assert!(!filter.contains(b"apple"));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn clear(&mut self) {
+        self.bits.fill(false);
+    }
+
+    /// Returns `true` if no item has been added since the `BloomFilter` was created or cleared.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if every bit is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3>::new();
+    /// assert!(filter.is_empty());
+    /// filter.insert(b"apple");
+    /// assert!(!filter.is_empty());
+    /// ```
+    /// 
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bits.is_all_zero()
+    }
+
+    /// Returns a reference to the bits of the `BloomFilter`.
+    ///
+    /// The bits can be stored or sent elsewhere and checked with [`count_ones`](Binvec::count_ones)
+    /// to estimate how full the filter is.
+    ///
+    /// ---
+    /// # Returns
+    /// The [`Binvec`] holding the filter bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut filter = BloomFilter::<1024, 128, 3>::new();
+    /// filter.insert(b"apple");
+    /// assert!(filter.as_binvec().count_ones() <= 3);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn as_binvec(&self) -> &Binvec<L, N> {
+        &self.bits
+    }
+}
+
+
+// impl Default
+impl<const L: usize, const N: usize, const K: usize, S: BuildHasher + Default> Default for BloomFilter<L, N, K, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+
+/// The default hasher of [`BloomFilter`], which only needs `core`.
+///
+/// It is 64-bit FNV-1a followed by a final avalanche step so that every output bit depends on every input bit.
+/// It is fast but not keyed, so use a keyed [`BuildHasher`] when the items may be chosen by an attacker.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+/// use core::hash::Hasher;
+///
+/// let mut hasher = BloomHasher::default();
+/// hasher.write(b"apple");
+/// assert_ne!(hasher.finish(), BloomHasher::default().finish());
+/// ```
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct BloomHasher {
+    state: u64,
+}
+
+
+// impl Default
+impl Default for BloomHasher {
+    #[inline]
+    fn default() -> Self {
+        Self { state: 0xcbf2_9ce4_8422_2325 } // FNV offset basis
+    }
+}
+
+
+// impl Hasher
+impl Hasher for BloomHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(0x0000_0100_0000_01b3); // FNV prime
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut hash: u64 = self.state; // SplitMix64 finalizer
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^ (hash >> 31)
+    }
+}
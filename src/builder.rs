@@ -0,0 +1,228 @@
+use crate::{Binvec, error};
+
+
+/// Assembles a [`Binvec`] field by field from index `0` upward.
+///
+/// Each push writes at a cursor that then moves past the written bits,
+/// and [`build`](BinvecBuilder::build) checks that exactly `L` bits were written.
+///
+/// ---
+/// # Generics
+/// - `L`: The number of bits of the finished [`Binvec`].
+/// - `N`: The minimum byte array length required to store `L`.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut frame = BinvecBuilder::<16, 2>::new();
+/// frame.push_bit(true).unwrap();
+/// frame.push_bits(0b101, 3).unwrap();
+/// frame.push_bits(0x0F, 4).unwrap();
+/// frame.push_bytes(&[0xA5]).unwrap();
+/// let frame: Binvec<16, 2> = frame.build().unwrap();
+/// assert_eq!(frame.as_bytes(), &[0b1111_1011, 0xA5]);
+/// ```
+/// 
+#[derive(Debug, Clone)]
+pub struct BinvecBuilder<const L: usize, const N: usize> {
+    binvec: Binvec<L, N>,
+    cursor: usize,
+}
+
+
+impl<const L: usize, const N: usize> BinvecBuilder<L, N> {
+    /// Creates a new `BinvecBuilder` with the cursor at index `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// An empty `BinvecBuilder`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let builder = BinvecBuilder::<16, 2>::new();
+    /// assert_eq!(builder.position(), 0);
+    /// ```
+    /// 
+    pub const fn new() -> Self {
+        Self { binvec: Binvec::from_inner([0x00; N]), cursor: 0 }
+    }
+
+    /// Returns the number of bits written so far, which is the index of the next bit to write.
+    ///
+    /// ---
+    /// # Returns
+    /// The cursor position, from `0` to `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<16, 2>::new();
+    /// builder.push_bits(0, 5).unwrap();
+    /// assert_eq!(builder.position(), 5);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the number of bits that can still be written.
+    ///
+    /// ---
+    /// # Returns
+    /// `L` minus the number of bits written so far.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<16, 2>::new();
+    /// builder.push_bits(0, 5).unwrap();
+    /// assert_eq!(builder.remaining(), 11);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        L - self.cursor
+    }
+
+    /// Writes one bit at the cursor.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bit`: The bit value to write (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was written.
+    /// - `Err(IndexOutOfBounds)` if all `L` bits were already written.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<1, 1>::new();
+    /// assert!(builder.push_bit(true).is_ok());
+    /// assert!(builder.push_bit(true).is_err());
+    /// ```
+    /// 
+    #[inline]
+    pub const fn push_bit(&mut self, bit: bool) -> Result<(), error::IndexOutOfBounds> {
+        self.push_bits(bit as u64, 1)
+    }
+
+    /// Writes the low `width` bits of `value` at the cursor, least significant bit first.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bits to write, where bit `i` goes to index `position() + i`.
+    /// - `width`: The number of bits to write, from 0 to 64.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bits were written.
+    /// - `Err(IndexOutOfBounds)` if `width` is greater than 64 or fewer than `width` bits remain. Nothing is written.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<128, 16>::new();
+    /// builder.push_bits(0b0110, 4).unwrap();
+    /// assert_eq!(builder.push_bits(0, 65), Err(error::IndexOutOfBounds));
+    /// assert_eq!(builder.position(), 4);
+    ///
+    /// let mut builder = BinvecBuilder::<8, 1>::new();
+    /// assert!(builder.push_bits(0, 9).is_err());
+    /// ```
+    /// 
+    pub const fn push_bits(&mut self, value: u64, width: usize) -> Result<(), error::IndexOutOfBounds> {
+        if width > 64
+        || width > L - self.cursor {
+            return Err(error::IndexOutOfBounds);
+        }
+        self.binvec.store_bits(self.cursor, width, value);
+        self.cursor += width;
+        Ok(())
+    }
+
+    /// Writes whole bytes at the cursor, each least significant bit first.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bytes`: The bytes to write, where bit `j` of byte `i` goes to index `position() + 8 * i + j`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bytes were written.
+    /// - `Err(IndexOutOfBounds)` if fewer than `8 * bytes.len()` bits remain. Nothing is written.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<16, 2>::new();
+    /// builder.push_bytes(&[0x12, 0x34]).unwrap();
+    /// assert_eq!(builder.build().unwrap().as_bytes(), &[0x12, 0x34]);
+    /// ```
+    /// 
+    pub const fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), error::IndexOutOfBounds> {
+        if bytes.len() > (L - self.cursor) >> 3 {
+            return Err(error::IndexOutOfBounds);
+        }
+        let mut i: usize = 0;
+        while i < bytes.len() {
+            self.binvec.store_bits(self.cursor, 8, bytes[i] as u64);
+            self.cursor += 8;
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Finishes the `BinvecBuilder` and returns the assembled [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(Binvec)` if exactly `L` bits were written.
+    /// - `Err(LengthMismatch)` if fewer bits were written.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut builder = BinvecBuilder::<4, 1>::new();
+    /// builder.push_bits(0b11, 2).unwrap();
+    /// assert_eq!(builder.clone().build(), Err(error::LengthMismatch));
+    /// builder.push_bits(0b01, 2).unwrap();
+    /// assert_eq!(builder.build().unwrap().as_bytes(), &[0b0111]);
+    /// ```
+    /// 
+    pub const fn build(self) -> Result<Binvec<L, N>, error::LengthMismatch> {
+        if self.cursor == L {
+            Ok(self.binvec)
+        } else {
+            Err(error::LengthMismatch)
+        }
+    }
+}
+
+
+// impl Default
+impl<const L: usize, const N: usize> Default for BinvecBuilder<L, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,25 @@
+use bytemuck::{CheckedBitPattern, NoUninit, Zeroable};
+use crate::Binvec;
+
+
+// impl Zeroable
+// SAFETY: `Binvec` is `#[repr(transparent)]` over `[u8; N]`, and all zeros is a valid empty vector.
+unsafe impl<const L: usize, const N: usize> Zeroable for Binvec<L, N> {}
+
+
+// impl NoUninit
+// SAFETY: `Binvec` is `#[repr(transparent)]` over `[u8; N]`, which has no padding bytes.
+unsafe impl<const L: usize, const N: usize> NoUninit for Binvec<L, N> {}
+
+
+// impl CheckedBitPattern
+// SAFETY: `Binvec` is `#[repr(transparent)]` over `[u8; N]`, the same layout as `Bits`.
+// `Binvec` is not `Pod`, so a cast from bytes must go through this check, which keeps the unused bits `0`.
+unsafe impl<const L: usize, const N: usize> CheckedBitPattern for Binvec<L, N> {
+    type Bits = [u8; N];
+
+    #[inline]
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+        Self::is_valid_bytes(bits)
+    }
+}
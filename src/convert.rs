@@ -0,0 +1,974 @@
+use crate::{error, Binvec};
+
+
+// impl conversions
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Creates a new [`Binvec`] from its packed byte representation.
+    ///
+    /// Bit `i` of the [`Binvec`] is bit `i % 8` of byte `i / 8`.
+    /// The unused bits beyond the length `L` in the last byte are cleared to zero.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bytes`: The packed bits, for example read back from storage.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length [`Binvec`] holding the bits of `bytes`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<12, 2>::from_raw_bytes([0b0000_0101, 0xFF]);
+    /// assert_eq!(binvec.get(0), Some(true));
+    /// assert_eq!(binvec.get(1), Some(false));
+    /// assert_eq!(binvec.count_ones(), 6);
+    /// ```
+    /// 
+    pub const fn from_raw_bytes(bytes: [u8; N]) -> Self {
+        let mut binvec: Binvec<L, N> = Self::from_inner(bytes);
+        binvec.clear_unused_bits();
+        binvec
+    }
+
+    /// Creates a new [`Binvec`] from its packed byte representation, rejecting non-zero padding.
+    ///
+    /// Unlike [`from_raw_bytes`](Binvec::from_raw_bytes), this does not silently clear
+    /// the unused bits beyond the length `L`, so corrupted external data is detected.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bytes`: The packed bits, where bit `i` is bit `i % 8` of byte `i / 8`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(Binvec)` if every unused bit in the last byte is `0`.
+    /// - `Err(NonZeroPadding)` otherwise.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<12, 2>::from_bytes([0xFF, 0x0F]).unwrap();
+    /// assert_eq!(binvec.count_ones(), 12);
+    ///
+    /// assert_eq!(Binvec::<12, 2>::from_bytes([0xFF, 0x1F]), Err(error::NonZeroPadding));
+    /// ```
+    /// 
+    pub const fn from_bytes(bytes: [u8; N]) -> Result<Self, error::NonZeroPadding> {
+        let binvec: Binvec<L, N> = Self::from_raw_bytes(bytes);
+        if N > 0
+        && binvec.inner[N - 1] != bytes[N - 1] {
+            return Err(error::NonZeroPadding);
+        }
+        Ok(binvec)
+    }
+
+    /// Views packed bytes as a [`Binvec`] without copying them, rejecting non-zero padding.
+    ///
+    /// This is the by-reference version of [`from_bytes`](Binvec::from_bytes), for example to read
+    /// a field of a larger buffer in place after taking a `&[u8; N]` out of it.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bytes`: The packed bits, where bit `i` is bit `i % 8` of byte `i / 8`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(&Binvec)` if every unused bit in the last byte is `0`.
+    /// - `Err(NonZeroPadding)` otherwise.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let packet: [u8; 4] = [0xAA, 0xFF, 0x0F, 0x55];
+    /// let field: &[u8; 2] = packet[1..3].try_into().unwrap();
+    /// let binvec = Binvec::<12, 2>::from_bytes_ref(field).unwrap();
+    /// assert_eq!(binvec.count_ones(), 12);
+    ///
+    /// assert_eq!(Binvec::<12, 2>::from_bytes_ref(&[0xFF, 0x1F]), Err(error::NonZeroPadding));
+    /// ```
+    /// 
+    pub const fn from_bytes_ref(bytes: &[u8; N]) -> Result<&Self, error::NonZeroPadding> {
+        const { assert!(N == L.div_ceil(8), "N must be (L + 7) / 8, the number of bytes needed to store L bits") };
+        if !Self::is_valid_bytes(bytes) {
+            return Err(error::NonZeroPadding);
+        }
+        // SAFETY: `Binvec` is `#[repr(transparent)]` over `[u8; N]`, and the unused bits were checked above
+        Ok(unsafe { &*(bytes as *const [u8; N] as *const Self) })
+    }
+
+    /// Creates a new [`Binvec`] from bytes packed most significant bit first.
+    ///
+    /// Bit `i` of the [`Binvec`] is bit `7 - i % 8` of byte `i / 8`, the order used by most wire protocols.
+    /// The bits after the length `L` in the last byte, which are its lowest bits, are ignored.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bytes`: The packed bits, first bit in the most significant position.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length [`Binvec`] holding the bits of `bytes`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<12, 2>::from_msb0_bytes([0b1000_0000, 0b0001_0000]);
+    /// assert_eq!(binvec.get(0), Some(true));
+    /// assert_eq!(binvec.get(11), Some(true));
+    /// assert_eq!(binvec.count_ones(), 2);
+    /// ```
+    /// 
+    pub const fn from_msb0_bytes(bytes: [u8; N]) -> Self {
+        let mut inner: [u8; N] = bytes;
+        let mut i: usize = 0;
+        while i < N {
+            inner[i] = inner[i].reverse_bits();
+            i += 1;
+        }
+        Self::from_raw_bytes(inner)
+    }
+
+    /// Returns the bits packed most significant bit first.
+    ///
+    /// This is the inverse of [`from_msb0_bytes`](Binvec::from_msb0_bytes).
+    ///
+    /// ---
+    /// # Returns
+    /// A byte array where bit `i` is bit `7 - i % 8` of byte `i / 8`. The bits after the length `L` are `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(0, true).unwrap();
+    /// binvec.set(11, true).unwrap();
+    /// assert_eq!(binvec.to_msb0_bytes(), [0b1000_0000, 0b0001_0000]);
+    /// ```
+    /// 
+    pub const fn to_msb0_bytes(&self) -> [u8; N] {
+        let mut bytes: [u8; N] = self.inner;
+        let mut i: usize = 0;
+        while i < N {
+            bytes[i] = bytes[i].reverse_bits();
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Consumes the [`Binvec`] and returns its packed byte representation.
+    ///
+    /// ---
+    /// # Returns
+    /// The backing byte array. The unused bits beyond the length `L` are always `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.into_inner(), [0xFF, 0x0F]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.inner
+    }
+
+    /// Returns a reference to the packed byte representation of the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// The backing byte array. The unused bits beyond the length `L` are always `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.as_bytes(), &[0xFF, 0x0F]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.inner
+    }
+
+    /// Gives a closure mutable access to the packed byte representation of the [`Binvec`].
+    ///
+    /// The closure may write any value to any byte. The unused bits beyond the length `L`
+    /// in the last byte are cleared once it returns, so they stay `0` as every other method expects.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `f`: The closure that reads and writes the backing byte array.
+    ///
+    /// ---
+    /// # Returns
+    /// The value returned by `f`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.with_bytes_mut(|bytes| *bytes = [0xFF, 0xFF]);
+    /// assert_eq!(binvec.as_bytes(), &[0xFF, 0x0F]);
+    /// assert_eq!(binvec.count_zeros(), 0);
+    /// ```
+    /// 
+    #[inline]
+    pub fn with_bytes_mut<R, F>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut [u8; N]) -> R,
+    {
+        let result: R = f(&mut self.inner);
+        self.clear_unused_bits();
+        result
+    }
+
+    /// Creates a new [`Binvec`] from a [`bool`] array.
+    ///
+    /// This can be evaluated at compile time, so it is also usable in `const` and `static` items.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `bits`: The bits to store, where `bits[i]` becomes the bit at index `i`.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length [`Binvec`] holding the same values as `bits`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// const BINVEC: Binvec<4, 1> = Binvec::from_bool_array([true, false, true, true]);
+    /// assert_eq!(BINVEC.count_ones(), 3);
+    /// assert_eq!(BINVEC.get(1), Some(false));
+    /// ```
+    /// 
+    pub const fn from_bool_array(bits: [bool; L]) -> Self {
+        let mut inner: [u8; N] = [0x00; N];
+        let mut i: usize = 0;
+        while i < L {
+            if bits[i] {
+                inner[i >> 3] |= 1 << (i & 0b111);
+            }
+            i += 1;
+        }
+        Self::from_inner(inner)
+    }
+
+    /// Parses a string of `'0'` and `'1'` characters for the string form of [`binvec!`](crate::binvec!).
+    ///
+    /// The first character becomes the bit at index `0`, the same as [`FromStr`](core::str::FromStr).
+    /// Any other character panics, which is a compile error when evaluated in a `const` item.
+    /// 
+    #[doc(hidden)]
+    pub const fn __from_bit_str(s: &str) -> Self {
+        let bytes: &[u8] = s.as_bytes();
+        assert!(bytes.len() == L, "the string length must match the length of the binvec");
+        let mut inner: [u8; N] = [0x00; N];
+        let mut i: usize = 0;
+        while i < L {
+            match bytes[i] {
+                b'0' => {}
+                b'1' => inner[i >> 3] |= 1 << (i & 0b111),
+                _ => panic!("expected only `0` and `1` characters in the `binvec!` string"),
+            }
+            i += 1;
+        }
+        Self::from_inner(inner)
+    }
+
+    /// Creates a new [`Binvec`] with the bits at the given indices set to `1` and every other bit `0`.
+    ///
+    /// This can be evaluated at compile time, so masks can be written as index lists in `const` items,
+    /// where an index out of range becomes a compile error.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `indices`: The indices of the bits to set. Repeated indices are allowed.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length [`Binvec`] with exactly the listed bits set to `1`.
+    ///
+    /// ---
+    /// # Panics
+    /// Panics if any index is not less than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// const IRQ_ENABLE: Binvec<32, 4> = Binvec::from_indices(&[0, 3, 17]);
+    /// assert_eq!(IRQ_ENABLE.iter_ones().collect::<Vec<_>>(), [0, 3, 17]);
+    /// ```
+    /// 
+    pub const fn from_indices(indices: &[usize]) -> Self {
+        let mut inner: [u8; N] = [0x00; N];
+        let mut i: usize = 0;
+        while i < indices.len() {
+            let index: usize = indices[i];
+            assert!(index < L, "index out of bounds");
+            inner[index >> 3] |= 1 << (index & 0b111);
+            i += 1;
+        }
+        Self::from_inner(inner)
+    }
+
+    /// Creates a new [`Binvec`] by repeating a bit pattern across its whole length.
+    ///
+    /// Bit `i` of the [`Binvec`] is bit `i % width` of `pattern`, and the repetition is cut off at `L`.
+    /// When `width` divides 64, the pattern is tiled into a [`u64`] once and copied byte by byte.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `pattern`: The bits to repeat, with the bit for index `0` in the least significant position.
+    /// - `width`: The number of low bits of `pattern` in one repetition, from 1 to 64.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L` length [`Binvec`] filled with the repeated pattern.
+    ///
+    /// ---
+    /// # Panics
+    /// Panics if `width` is `0` or greater than 64.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let alternating = Binvec::<12, 2>::from_pattern(0b01, 2);
+    /// assert_eq!(alternating.as_bytes(), &[0b0101_0101, 0b0000_0101]);
+    ///
+    /// let stripes = Binvec::<10, 2>::from_pattern(0b011, 3);
+    /// assert_eq!(stripes.iter_ones().collect::<Vec<_>>(), [0, 1, 3, 4, 6, 7, 9]);
+    /// ```
+    /// 
+    pub const fn from_pattern(pattern: u64, width: usize) -> Self {
+        assert!(width > 0 && width <= 64, "width must be from 1 to 64");
+        let mut inner: [u8; N] = [0x00; N];
+        if 64 % width == 0 {
+            let mut tile: u64 = if width == 64 { pattern } else { pattern & ((1 << width) - 1) };
+            let mut filled: usize = width;
+            while filled < 64 {
+                tile |= tile << filled;
+                filled <<= 1;
+            }
+            let bytes: [u8; 8] = tile.to_le_bytes();
+            let mut i: usize = 0;
+            while i < N {
+                inner[i] = bytes[i & 0b111];
+                i += 1;
+            }
+        } else {
+            let mut i: usize = 0;
+            while i < L {
+                if (pattern >> (i % width)) & 1 != 0 {
+                    inner[i >> 3] |= 1 << (i & 0b111);
+                }
+                i += 1;
+            }
+        }
+        let mut binvec: Binvec<L, N> = Self::from_inner(inner);
+        binvec.clear_unused_bits();
+        binvec
+    }
+
+    /// Returns the bits of the [`Binvec`] as a [`bool`] array.
+    ///
+    /// ---
+    /// # Returns
+    /// An array where element `i` is the bit at index `i`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(3, false);
+    /// binvec.set(1, true).unwrap();
+    /// assert_eq!(binvec.to_bool_array(), [false, true, false]);
+    /// ```
+    /// 
+    pub const fn to_bool_array(&self) -> [bool; L] {
+        let mut bits: [bool; L] = [false; L];
+        let mut i: usize = 0;
+        while i < L {
+            // SAFETY: i is guaranteed to be less than L
+            bits[i] = unsafe { self.get_unchecked(i) };
+            i += 1;
+        }
+        bits
+    }
+
+    /// Returns the bits of the [`Binvec`] as a [`u64`], where the bit at index `i` becomes bit `i`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(value)` if `L` is at most 64. The bits above `L` are `0`.
+    /// - `None` if `L` is greater than 64.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(0, true).unwrap();
+    /// binvec.set(11, true).unwrap();
+    /// assert_eq!(binvec.to_u64(), Some(0x801));
+    ///
+    /// assert_eq!(binvec!(65, false).to_u64(), None);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn to_u64(&self) -> Option<u64> {
+        if L <= 64 {
+            Some(self.load_word(0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bits of the [`Binvec`] as a [`u128`], where the bit at index `i` becomes bit `i`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(value)` if `L` is at most 128. The bits above `L` are `0`.
+    /// - `None` if `L` is greater than 128.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(100, false);
+    /// binvec.set(99, true).unwrap();
+    /// assert_eq!(binvec.to_u128(), Some(1 << 99));
+    ///
+    /// assert_eq!(binvec!(129, false).to_u128(), None);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn to_u128(&self) -> Option<u128> {
+        if L <= 128 {
+            Some((self.load_word(0) as u128) | ((self.load_word(8) as u128) << 64))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the [`Binvec`] to a longer one, filling the new high bits with `0`.
+    ///
+    /// The bytes are copied whole. The lengths are checked at compile time.
+    ///
+    /// ---
+    /// # Generics
+    /// - `L2`: The new length, which must be at least `L`.
+    /// - `N2`: The new byte length, which must be `(L2 + 7) / 8`. It can be inferred from an annotated result type.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L2` length [`Binvec`] holding the bits of `self` at `[0, L)` and `0` at `[L, L2)`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(100, true);
+    /// let wide: Binvec<128, 16> = binvec.zero_extend();
+    /// assert_eq!(wide.count_ones(), 100);
+    /// assert_eq!(wide.leading_zeros(), 28);
+    /// ```
+    /// 
+    pub const fn zero_extend<const L2: usize, const N2: usize>(&self) -> Binvec<L2, N2> {
+        const {
+            assert!(L2 >= L, "`zero_extend` cannot shorten a `Binvec`, use `truncate` instead");
+            assert!(N2 == (L2 + 7) >> 3, "`N2` must be `(L2 + 7) / 8`");
+        };
+        let mut binvec: Binvec<L2, N2> = Binvec::from_inner([0x00; N2]);
+        let mut i: usize = 0;
+        while i < N {
+            binvec.inner[i] = self.inner[i]; // unused bits of `self` are already 0
+            i += 1;
+        }
+        binvec
+    }
+
+    /// Converts the [`Binvec`] to a shorter one, dropping the bits at index `L2` and above.
+    ///
+    /// The bytes are copied whole and the unused bits of the result are cleared.
+    /// The lengths are checked at compile time.
+    ///
+    /// ---
+    /// # Generics
+    /// - `L2`: The new length, which must be at most `L`.
+    /// - `N2`: The new byte length, which must be `(L2 + 7) / 8`. It can be inferred from an annotated result type.
+    ///
+    /// ---
+    /// # Returns
+    /// An `L2` length [`Binvec`] holding the bits of `self` at `[0, L2)`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(128, true);
+    /// let narrow = binvec.truncate::<100, 13>();
+    /// assert_eq!(narrow.count_ones(), 100);
+    /// ```
+    /// 
+    pub const fn truncate<const L2: usize, const N2: usize>(&self) -> Binvec<L2, N2> {
+        const {
+            assert!(L2 <= L, "`truncate` cannot lengthen a `Binvec`, use `zero_extend` instead");
+            assert!(N2 == (L2 + 7) >> 3, "`N2` must be `(L2 + 7) / 8`");
+        };
+        let mut binvec: Binvec<L2, N2> = Binvec::from_inner([0x00; N2]);
+        let mut i: usize = 0;
+        while i < N2 {
+            binvec.inner[i] = self.inner[i];
+            i += 1;
+        }
+        binvec.clear_unused_bits();
+        binvec
+    }
+
+    /// Converts the [`Binvec`] from binary to reflected Gray code.
+    ///
+    /// The [`Binvec`] is seen as an `L` bit unsigned integer with the least significant bit at index `0`,
+    /// and the result is `self ^ (self >> 1)`, so consecutive integers differ in exactly one bit.
+    ///
+    /// ---
+    /// # Returns
+    /// The Gray code of `self`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<4, 1>::from_raw_bytes([0b0111]); // 7
+    /// assert_eq!(binvec.to_gray().as_bytes(), &[0b0100]);
+    /// assert_eq!(binvec.to_gray().from_gray(), binvec);
+    /// ```
+    /// 
+    pub const fn to_gray(&self) -> Self {
+        let mut gray: Binvec<L, N> = *self;
+        let mut i: usize = 0;
+        while i < N {
+            let next_low: u8 = if i + 1 < N { self.inner[i + 1] << 7 } else { 0x00 }; // lowest bit of the next byte
+            gray.inner[i] = self.inner[i] ^ ((self.inner[i] >> 1) | next_low);
+            i += 1;
+        }
+        gray
+    }
+
+    /// Converts the [`Binvec`] from reflected Gray code back to binary.
+    ///
+    /// This is the inverse of [`to_gray`](Binvec::to_gray): each bit of the result is the XOR of the bits at its index and above.
+    /// The bytes are processed from the highest index down, carrying the parity of the bits above each byte.
+    ///
+    /// ---
+    /// # Returns
+    /// The binary value whose Gray code is `self`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let gray = Binvec::<4, 1>::from_raw_bytes([0b0100]);
+    /// assert_eq!(gray.from_gray().to_u64(), Some(7));
+    /// ```
+    /// 
+    pub const fn from_gray(&self) -> Self {
+        let mut binary: Binvec<L, N> = *self;
+        let mut parity: bool = false;
+        let mut i: usize = N;
+        while i > 0 {
+            i -= 1;
+            let mut byte: u8 = self.inner[i];
+            byte ^= byte >> 1;
+            byte ^= byte >> 2;
+            byte ^= byte >> 4; // bit `j` is now the XOR of bits `j..8`
+            if parity {
+                byte = !byte;
+            }
+            binary.inner[i] = byte;
+            parity = byte & 1 != 0;
+        }
+        binary // the first byte processed is the last one, whose unused bits stay 0
+    }
+
+    /// Gathers the bits of `self` at the set positions of `mask` into the low indices of the result, like the x86 `PEXT` instruction.
+    ///
+    /// The selected bits keep their order, so the bit at the `k`-th set position of `mask` becomes the bit at index `k`,
+    /// and the bits from index `mask.count_ones()` upward are 0.
+    /// The bits are gathered 64 at a time, and [`to_u64`](Binvec::to_u64) or [`truncate`](Binvec::truncate) narrows the result.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `mask`: The [`Binvec`] whose set bits select the bits to gather.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the selected bits from index `0` upward.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let word = Binvec::<16, 2>::from_raw_bytes([0b1010_1100, 0b0000_0001]);
+    /// let mask = Binvec::<16, 2>::from_raw_bytes([0b1111_0000, 0b0000_0001]); // a 5 bit field at index 4 and 8
+    /// assert_eq!(word.extract_under_mask(&mask).to_u64(), Some(0b11010));
+    /// ```
+    /// 
+    pub const fn extract_under_mask(&self, mask: &Self) -> Self {
+        let mut extracted: Binvec<L, N> = Self::from_inner([0x00; N]);
+        let mut written: usize = 0;
+        let mut start: usize = 0;
+        while start < L {
+            let len: usize = if L - start < 64 { L - start } else { 64 };
+            let word: u64 = self.load_bits(start, len);
+            let mut mask_word: u64 = mask.load_bits(start, len);
+            let mut gathered: u64 = 0;
+            let mut count: usize = 0;
+            while mask_word != 0 {
+                let lowest: u64 = mask_word & mask_word.wrapping_neg();
+                if word & lowest != 0 {
+                    gathered |= 1 << count;
+                }
+                count += 1;
+                mask_word &= mask_word - 1; // clear the lowest set bit
+            }
+            extracted.store_bits(written, count, gathered);
+            written += count;
+            start += len;
+        }
+        extracted
+    }
+
+    /// Parses a hexadecimal string into a [`Binvec`].
+    ///
+    /// The string is read as a number with the most significant digit first,
+    /// where bit `i` of the number becomes the bit at index `i`.
+    /// It must hold exactly `(L + 3) / 4` digits, which is what [`to_hex_string`](Binvec::to_hex_string) produces.
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `s`: The hexadecimal digits, without any prefix.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(Binvec)` holding the parsed bits.
+    /// - `Err(ParseBinvecError)` if the string has an invalid character,
+    ///   the wrong number of digits, or bits set beyond the length `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<10, 2>::from_hex_str("2a5").unwrap();
+    /// assert_eq!(binvec.to_u64(), Some(0x2A5));
+    ///
+    /// assert_eq!(Binvec::<10, 2>::from_hex_str("4a5"), Err(error::ParseBinvecError::Overflow));
+    /// ```
+    /// 
+    pub fn from_hex_str(s: &str) -> Result<Self, error::ParseBinvecError> {
+        let digits: usize = (L + 3) >> 2; // same as (L + 3) / 4
+        let found: usize = s.chars().count();
+        if found != digits {
+            return Err(error::ParseBinvecError::LengthMismatch { expected: digits, found });
+        }
+        let mut inner: [u8; N] = [0x00; N];
+        for (position, character) in s.chars().enumerate() {
+            let nibble: u8 = match character.to_digit(16) {
+                Some(nibble) => nibble as u8,
+                None => return Err(error::ParseBinvecError::InvalidCharacter { position, character }),
+            };
+            let start: usize = (digits - 1 - position) << 2; // the first digit is the most significant
+            if L - start < 4
+            && nibble >> (L - start) != 0 {
+                return Err(error::ParseBinvecError::Overflow);
+            }
+            inner[start >> 3] |= nibble << (start & 0b111);
+        }
+        Ok(Self::from_inner(inner))
+    }
+
+    /// Writes the [`Binvec`] as a hexadecimal number to a [`core::fmt::Write`] sink.
+    ///
+    /// Bit `i` of the number is the bit at index `i`, and the most significant digit is written first.
+    /// Exactly `(L + 3) / 4` lowercase digits are written, including leading zeros,
+    /// so the output can be parsed back with [`from_hex_str`](Binvec::from_hex_str).
+    ///
+    /// ---
+    /// # Arguments
+    /// - `w`: The sink to write the digits to.
+    ///
+    /// ---
+    /// # Returns
+    /// The result of the underlying writes.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<10, 2>::from_raw_bytes([0xA5, 0x02]);
+    /// let mut hex = String::new();
+    /// binvec.write_hex(&mut hex).unwrap();
+    /// assert_eq!(hex, "2a5");
+    /// ```
+    /// 
+    pub fn write_hex<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let digits: usize = (L + 3) >> 2; // same as (L + 3) / 4
+        for digit in (0..digits).rev() {
+            let start: usize = digit << 2;
+            let nibble: u8 = (self.inner[start >> 3] >> (start & 0b111)) & 0x0F; // unused bits are always 0
+            w.write_char(DIGITS[nibble as usize] as char)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the [`Binvec`] as a hexadecimal string.
+    ///
+    /// This is the owned form of [`write_hex`](Binvec::write_hex),
+    /// and the result can be parsed back with [`from_hex_str`](Binvec::from_hex_str).
+    ///
+    /// ---
+    /// # Returns
+    /// A string of exactly `(L + 3) / 4` lowercase digits, most significant first.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(10, true);
+    /// assert_eq!(binvec.to_hex_string(), "3ff");
+    /// assert_eq!(Binvec::<10, 2>::from_hex_str("3ff"), Ok(binvec));
+    /// ```
+    /// 
+    pub fn to_hex_string(&self) -> String {
+        let mut hex: String = String::with_capacity((L + 3) >> 2);
+        self.write_hex(&mut hex).expect("writing to a `String` never fails");
+        hex
+    }
+
+    /// Creates a new [`Binvec`] from an iterator of bits.
+    ///
+    /// The bits are packed a whole byte at a time, and the iterator is consumed
+    /// until it is exhausted or it is known to be longer than `L`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `iter`: The bits to store, starting from index `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(Binvec)` if the iterator yielded exactly `L` bits.
+    /// - `Err(LengthMismatch)` if it yielded fewer or more bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<4, 1>::try_from_iter([true, false, true, true]).unwrap();
+    /// assert_eq!(binvec.count_ones(), 3);
+    ///
+    /// assert_eq!(Binvec::<4, 1>::try_from_iter([true, false]), Err(error::LengthMismatch));
+    /// ```
+    /// 
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, error::LengthMismatch>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut binvec: Binvec<L, N> = Self::from_inner([0x00; N]);
+        binvec.assign_from_iter(iter)?;
+        Ok(binvec)
+    }
+
+    /// Overwrites the whole [`Binvec`] with the bits of an iterator, from index `0` upward.
+    ///
+    /// The bits are packed a whole byte at a time into a copy, which replaces the [`Binvec`] only if
+    /// the iterator yielded exactly `L` bits. The iterator is consumed until it is exhausted or it is known to be longer than `L`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `iter`: The bits to write, starting from index `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(usize)` with the number of bits written, which is `L`, if the iterator yielded exactly `L` bits.
+    /// - `Err(IterLengthMismatch::TooShort { len })` if it yielded only `len` bits. The [`Binvec`] is left unchanged.
+    /// - `Err(IterLengthMismatch::TooLong)` if it yielded more than `L` bits. The [`Binvec`] is left unchanged.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(6, true);
+    /// assert_eq!(binvec.assign_from_iter([false, true, false, false, true, false]), Ok(6));
+    /// assert_eq!(binvec.as_bytes(), &[0b010010]);
+    ///
+    /// assert_eq!(binvec.assign_from_iter([true; 3]), Err(error::IterLengthMismatch::TooShort { len: 3 }));
+    /// assert_eq!(binvec.assign_from_iter([true; 7]), Err(error::IterLengthMismatch::TooLong));
+    /// assert_eq!(binvec.as_bytes(), &[0b010010]);
+    /// ```
+    /// 
+    pub fn assign_from_iter<I>(&mut self, iter: I) -> Result<usize, error::IterLengthMismatch>
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut iter = iter.into_iter();
+        let mut inner: [u8; N] = [0x00; N];
+        for (byte_index, byte) in inner.iter_mut().enumerate() {
+            let start: usize = byte_index << 3;
+            let bits: usize = if L - start < 8 { L - start } else { 8 };
+            for bit_offset in 0..bits {
+                match iter.next() {
+                    Some(true) => *byte |= 1 << bit_offset,
+                    Some(false) => {}
+                    None => return Err(error::IterLengthMismatch::TooShort { len: start + bit_offset }),
+                }
+            }
+        }
+        match iter.next() {
+            Some(_) => Err(error::IterLengthMismatch::TooLong),
+            None => {
+                self.inner = inner;
+                Ok(L)
+            }
+        }
+    }
+}
+
+
+// impl From
+impl<const L: usize, const N: usize> From<[bool; L]> for Binvec<L, N> {
+    #[inline]
+    fn from(bits: [bool; L]) -> Self {
+        Self::from_bool_array(bits)
+    }
+}
+
+
+impl<const L: usize, const N: usize> From<Binvec<L, N>> for [bool; L] {
+    #[inline]
+    fn from(binvec: Binvec<L, N>) -> Self {
+        binvec.to_bool_array()
+    }
+}
+
+
+// impl TryFrom
+impl<const L: usize, const N: usize> TryFrom<&[bool]> for Binvec<L, N> {
+    type Error = error::LengthMismatch;
+
+    /// Converts a [`bool`] slice of exactly `L` elements into a [`Binvec`].
+    fn try_from(bits: &[bool]) -> Result<Self, Self::Error> {
+        if bits.len() != L {
+            return Err(error::LengthMismatch);
+        }
+        Self::try_from_iter(bits.iter().copied())
+    }
+}
+
+
+// impl FromStr
+impl<const L: usize, const N: usize> core::str::FromStr for Binvec<L, N> {
+    type Err = error::ParseBinvecError;
+
+    /// Parses a string of `'0'` and `'1'` characters,
+    /// where the first character becomes the bit at index `0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inner: [u8; N] = [0x00; N];
+        let mut found: usize = 0;
+        for (position, character) in s.chars().enumerate() {
+            let bit: bool = match character {
+                '0' => false,
+                '1' => true,
+                _ => return Err(error::ParseBinvecError::InvalidCharacter { position, character }),
+            };
+            if bit
+            && position < L {
+                inner[position >> 3] |= 1 << (position & 0b111);
+            }
+            found += 1;
+        }
+        if found != L {
+            return Err(error::ParseBinvecError::LengthMismatch { expected: L, found });
+        }
+        Ok(Self::from_inner(inner))
+    }
+}
+
+
+// impl From<uint>
+macro_rules! impl_from_uint {
+    ($($uint:ty => $len:literal, $bytes:literal);* $(;)?) => {$(
+        impl From<$uint> for Binvec<$len, $bytes> {
+            /// Converts an integer into a [`Binvec`] of the same bit width,
+            /// where bit `i` of the integer becomes the bit at index `i`.
+            #[inline]
+            fn from(value: $uint) -> Self {
+                Self::from_raw_bytes(value.to_le_bytes())
+            }
+        }
+    )*};
+}
+
+impl_from_uint! {
+    u8 => 8, 1;
+    u16 => 16, 2;
+    u32 => 32, 4;
+    u64 => 64, 8;
+    u128 => 128, 16;
+}
+
+
+// impl FromIterator
+impl<const L: usize, const N: usize> FromIterator<bool> for Binvec<L, N> {
+    /// Collects exactly `L` bits into a [`Binvec`].
+    ///
+    /// ---
+    /// # Panics
+    /// Panics if the iterator does not yield exactly `L` bits.
+    /// Use [`Binvec::try_from_iter`] to handle that case without panicking.
+    /// 
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(binvec) => binvec,
+            Err(_) => panic!("the iterator did not yield exactly {L} bits"),
+        }
+    }
+}
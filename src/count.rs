@@ -0,0 +1,269 @@
+use core::ops::Range;
+use crate::Binvec;
+use crate::kernel::{self, Combine};
+
+
+// impl counts
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Counts the bits that differ between two [`Binvec`]s.
+    ///
+    /// The bytes are XORed and counted a [`u64`] word at a time, so no intermediate [`Binvec`] is built.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of indices where the two [`Binvec`]s hold different bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = binvec!(12, false);
+    /// let mut b = binvec!(12, false);
+    /// b.set(1, true).unwrap();
+    /// b.set(10, true).unwrap();
+    /// assert_eq!(a.hamming_distance(&b), 2);
+    /// ```
+    /// 
+    pub const fn hamming_distance(&self, other: &Self) -> usize {
+        kernel::count_ones_combined(&self.inner, &other.inner, Combine::Xor) // unused bits are 0 on both sides
+    }
+
+    /// Returns the parity of the [`Binvec`], the XOR of all of its bits.
+    ///
+    /// The bytes are folded together with XOR a word at a time and only the folded word is popcounted.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if an odd number of bits are set to `1`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.parity(), true);
+    /// binvec.set(11, true).unwrap();
+    /// assert_eq!(binvec.parity(), false);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn parity(&self) -> bool {
+        kernel::parity(&self.inner) // unused bits are always 0
+    }
+
+    /// Checks whether at least `n` bits are set to `1`, stopping as soon as the answer is known.
+    ///
+    /// The scan ends once `n` ones are found, or once the bits left could no longer reach `n`,
+    /// so it is cheaper than comparing [`count_ones`](Binvec::count_ones) on large, mostly full vectors.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The required number of bits set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if `count_ones() >= n`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(10_000, true);
+    /// assert_eq!(binvec.has_at_least(64), true);
+    /// assert_eq!(binvec.has_at_least(10_001), false);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn has_at_least(&self, n: usize) -> bool {
+        n <= L
+        && kernel::count_ones_reaches(&self.inner, n)
+    }
+
+    /// Checks whether at most `n` bits are set to `1`, stopping as soon as the answer is known.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The allowed number of bits set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if `count_ones() <= n`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(10_000, true);
+    /// assert_eq!(binvec.has_at_most(64), false);
+    /// assert_eq!(binvec.has_at_most(10_000), true);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn has_at_most(&self, n: usize) -> bool {
+        n >= L
+        || !kernel::count_ones_reaches(&self.inner, n + 1)
+    }
+
+    /// Counts the bits set to `1` in `self & other` without building the intermediate [`Binvec`].
+    ///
+    /// This is the size of the intersection when the [`Binvec`]s are seen as sets.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of `1` bits in `self & other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a: Binvec<12, 2> = "110011001100".parse().unwrap();
+    /// let b: Binvec<12, 2> = "101010101010".parse().unwrap();
+    /// assert_eq!(a.and_count(&b), 3);
+    /// ```
+    /// 
+    pub const fn and_count(&self, other: &Self) -> usize {
+        kernel::count_ones_combined(&self.inner, &other.inner, Combine::And)
+    }
+
+    /// Counts the bits set to `1` in `self | other` without building the intermediate [`Binvec`].
+    ///
+    /// This is the size of the union when the [`Binvec`]s are seen as sets.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of `1` bits in `self | other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a: Binvec<12, 2> = "110011001100".parse().unwrap();
+    /// let b: Binvec<12, 2> = "101010101010".parse().unwrap();
+    /// assert_eq!(a.or_count(&b), 9);
+    /// ```
+    /// 
+    pub const fn or_count(&self, other: &Self) -> usize {
+        kernel::count_ones_combined(&self.inner, &other.inner, Combine::Or)
+    }
+
+    /// Counts the bits set to `1` in `self ^ other` without building the intermediate [`Binvec`].
+    ///
+    /// This is the same as [`Binvec::hamming_distance`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of `1` bits in `self ^ other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a: Binvec<12, 2> = "110011001100".parse().unwrap();
+    /// let b: Binvec<12, 2> = "101010101010".parse().unwrap();
+    /// assert_eq!(a.xor_count(&b), 6);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn xor_count(&self, other: &Self) -> usize {
+        self.hamming_distance(other)
+    }
+
+    /// Counts the bits set to `1` in `self & !other` without building the intermediate [`Binvec`].
+    ///
+    /// This is the size of the set difference when the [`Binvec`]s are seen as sets.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of `1` bits in `self & !other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a: Binvec<12, 2> = "110011001100".parse().unwrap();
+    /// let b: Binvec<12, 2> = "101010101010".parse().unwrap();
+    /// assert_eq!(a.andnot_count(&b), 3);
+    /// ```
+    /// 
+    pub const fn andnot_count(&self, other: &Self) -> usize {
+        kernel::count_ones_combined(&self.inner, &other.inner, Combine::AndNot)
+    }
+
+    /// Counts the bits set to `1` in the given range.
+    ///
+    /// The partial first and last bytes are masked, and the bytes in between are counted whole.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to count.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(count)` of the bits that are `1` in `start..end`.
+    /// - `None` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<20, 3> = "11110000111100001111".parse().unwrap();
+    /// assert_eq!(binvec.count_ones_in_range(2..10), Some(4));
+    /// assert_eq!(binvec.count_ones_in_range(5..5), Some(0));
+    /// assert_eq!(binvec.count_ones_in_range(10..21), None);
+    /// ```
+    /// 
+    pub const fn count_ones_in_range(&self, range: Range<usize>) -> Option<usize> {
+        let start: usize = range.start;
+        let end: usize = range.end;
+        if start > end
+        || end > L {
+            return None;
+        }
+        if start == end {
+            return Some(0);
+        }
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            return Some((self.inner[start_byte] & start_mask & end_mask).count_ones() as usize);
+        }
+        let mut count: usize = (self.inner[start_byte] & start_mask).count_ones() as usize;
+        count += kernel::count_ones(self.inner.split_at(end_byte).0.split_at(start_byte + 1).1);
+        if end_mask != 0x00 {
+            count += (self.inner[end_byte] & end_mask).count_ones() as usize;
+        }
+        Some(count)
+    }
+}
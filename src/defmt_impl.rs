@@ -0,0 +1,15 @@
+use defmt::{Format, Formatter};
+use crate::Binvec;
+
+
+// impl Format
+impl<const L: usize, const N: usize> Format for Binvec<L, N> {
+    /// Logs the length and the packed bytes, such as `Binvec { len: 12, bytes: [11111111, 00001111] }`.
+    ///
+    /// Only the `N` bytes are sent over the wire and formatting happens on the host.
+    /// Each byte is printed in binary with the lowest index as its rightmost digit.
+    /// 
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "Binvec {{ len: {=usize}, bytes: {=[u8]:08b} }}", L, &self.inner[..]);
+    }
+}
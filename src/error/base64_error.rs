@@ -0,0 +1,36 @@
+/// Failed to decode a `Binvec` from a base64 string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeBase64Error {
+    /// The string is not valid base64.
+    InvalidBase64,
+    /// The decoded data does not hold exactly the expected number of bytes.
+    LengthMismatch {
+        /// The number of bytes the `Binvec` stores.
+        expected: usize,
+        /// The number of bytes decoded from the string.
+        found: usize,
+    },
+    /// The unused bits beyond the length in the last byte are not zero.
+    NonZeroPadding,
+}
+
+
+impl core::fmt::Display for DecodeBase64Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidBase64 => {
+                write!(f, "invalid base64")
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} bytes but decoded {found}")
+            }
+            Self::NonZeroPadding => {
+                write!(f, "unused padding bits are not zero")
+            }
+        }
+    }
+}
+
+
+impl core::error::Error for DecodeBase64Error {}
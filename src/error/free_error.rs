@@ -0,0 +1,29 @@
+/// Failed to free a range of bits in a bitmap allocator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FreeError {
+    /// The range reaches beyond the length of the `Binvec`.
+    IndexOutOfBounds,
+    /// A bit in the range is already `0`, so the range was not fully allocated.
+    DoubleFree {
+        /// The index of the first bit in the range that is already free.
+        index: usize,
+    },
+}
+
+
+impl core::fmt::Display for FreeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds => {
+                write!(f, "index out of bounds")
+            }
+            Self::DoubleFree { index } => {
+                write!(f, "bit {index} is already free")
+            }
+        }
+    }
+}
+
+
+impl core::error::Error for FreeError {}
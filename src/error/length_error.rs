@@ -0,0 +1,52 @@
+/// Length mismatch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch;
+
+
+impl core::fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "length mismatch")
+    }
+}
+
+
+impl core::error::Error for LengthMismatch {}
+
+
+/// An iterator yielded a different number of bits than the length of the `Binvec`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IterLengthMismatch {
+    /// The iterator ran out before filling the `Binvec`.
+    TooShort {
+        /// The number of bits the iterator yielded.
+        len: usize,
+    },
+    /// The iterator still had bits after filling the `Binvec`.
+    TooLong,
+}
+
+
+impl core::fmt::Display for IterLengthMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort { len } => {
+                write!(f, "length mismatch: the iterator yielded only {len} bits")
+            }
+            Self::TooLong => {
+                write!(f, "length mismatch: the iterator yielded too many bits")
+            }
+        }
+    }
+}
+
+
+impl core::error::Error for IterLengthMismatch {}
+
+
+impl From<IterLengthMismatch> for LengthMismatch {
+    #[inline]
+    fn from(_: IterLengthMismatch) -> Self {
+        LengthMismatch
+    }
+}
@@ -2,3 +2,20 @@
 
 mod index_error;
 pub use index_error::*;
+
+mod length_error;
+pub use length_error::*;
+
+mod padding_error;
+pub use padding_error::*;
+
+mod parse_error;
+pub use parse_error::*;
+
+mod free_error;
+pub use free_error::*;
+
+#[cfg(feature = "base64")]
+mod base64_error;
+#[cfg(feature = "base64")]
+pub use base64_error::*;
@@ -0,0 +1,13 @@
+/// Unused bits beyond the length are not zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonZeroPadding;
+
+
+impl core::fmt::Display for NonZeroPadding {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unused padding bits are not zero")
+    }
+}
+
+
+impl core::error::Error for NonZeroPadding {}
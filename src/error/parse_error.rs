@@ -0,0 +1,41 @@
+/// Failed to parse a `Binvec` from a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseBinvecError {
+    /// The string contains a character that is not a valid digit.
+    InvalidCharacter {
+        /// The character position in the string.
+        position: usize,
+        /// The invalid character.
+        character: char,
+    },
+    /// The string does not hold exactly the expected number of digits.
+    LengthMismatch {
+        /// The number of digits needed for the length of the `Binvec`.
+        expected: usize,
+        /// The number of digits found in the string.
+        found: usize,
+    },
+    /// The string sets bits beyond the length of the `Binvec`.
+    Overflow,
+}
+
+
+impl core::fmt::Display for ParseBinvecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidCharacter { position, character } => {
+                write!(f, "invalid character {character:?} at position {position}")
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} digits but found {found}")
+            }
+            Self::Overflow => {
+                write!(f, "value sets bits beyond the length")
+            }
+        }
+    }
+}
+
+
+impl core::error::Error for ParseBinvecError {}
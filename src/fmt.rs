@@ -0,0 +1,177 @@
+use core::fmt::{Alignment, Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex, Write};
+use crate::Binvec;
+
+
+// impl Debug
+impl<const L: usize, const N: usize> Debug for Binvec<L, N> {
+    /// Formats the length, the number of `1` bits, and the bits in the compact form,
+    /// such as `Binvec { len: 4, ones: 3, bits: 1011 }`.
+    ///
+    /// A precision (`{:.P}`) elides the middle of long vectors like [`Display`] does.
+    /// 
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let precision: Option<usize> = f.precision();
+        let mut debug = f.debug_struct("Binvec");
+        debug.field("len", &L);
+        debug.field("ones", &self.count_ones());
+        match precision {
+            Some(precision) => debug.field("bits", &format_args!("{self:#.precision$}")),
+            None => debug.field("bits", &format_args!("{self:#}")),
+        };
+        debug.finish()
+    }
+}
+
+
+// impl Display
+impl<const L: usize, const N: usize> Display for Binvec<L, N> {
+    /// Formats the bits in index order.
+    ///
+    /// The default form is a list such as `[1, 0, 1]`.
+    /// The alternate form (`{:#}`) writes the digits contiguously such as `101`,
+    /// which is the same format accepted by [`FromStr`](core::str::FromStr).
+    ///
+    /// A precision (`{:.P}`) limits the output to at most `P` bits for long vectors:
+    /// the bits at both ends are kept and the middle is elided, such as `[1, 0, … 8 more …, 1]`.
+    /// In the alternate form, the bits are then also grouped in blocks of 8 by index, such as `10110010 01 … 6 more …`.
+    /// 
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let (head, tail): (usize, usize) = match f.precision() {
+            Some(precision) if precision < L => (precision.div_ceil(2), precision / 2),
+            _ => (L, 0),
+        };
+        let elided: usize = L - head - tail;
+        let grouped: bool = f.precision().is_some();
+        let shown = (0..head).chain((L - tail)..L);
+
+        if f.alternate() {
+            for index in shown {
+                if grouped
+                && index > 0
+                && index % 8 == 0
+                && index != L - tail {
+                    f.write_char(' ')?;
+                }
+                if elided > 0
+                && index == L - tail {
+                    write!(f, " … {elided} more … ")?;
+                }
+                // SAFETY: index is guaranteed to be less than L
+                f.write_char(if unsafe { self.get_unchecked(index) } { '1' } else { '0' })?;
+            }
+            if elided > 0
+            && tail == 0 {
+                write!(f, "{}… {elided} more …", if head > 0 { " " } else { "" })?;
+            }
+            return Ok(());
+        }
+
+        write!(f, "[")?;
+        for index in shown {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            if elided > 0
+            && index == L - tail {
+                write!(f, "… {elided} more …, ")?;
+            }
+            // SAFETY: index is guaranteed to be less than L
+            write!(f, "{}", if unsafe { self.get_unchecked(index) } { "1" } else { "0" })?;
+        }
+        if elided > 0
+        && tail == 0 {
+            write!(f, "{}… {elided} more …", if head > 0 { ", " } else { "" })?;
+        }
+        write!(f, "]")
+    }
+}
+
+
+// impl Binary
+impl<const L: usize, const N: usize> Binary for Binvec<L, N> {
+    /// Formats the bits as a binary number, with the bit at index `L - 1` as the most significant digit.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.fmt_radix(f, 1, "0b", b"01")
+    }
+}
+
+
+// impl Octal
+impl<const L: usize, const N: usize> Octal for Binvec<L, N> {
+    /// Formats the bits as an octal number, with the bit at index `L - 1` as the most significant digit.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.fmt_radix(f, 3, "0o", b"01234567")
+    }
+}
+
+
+// impl LowerHex
+impl<const L: usize, const N: usize> LowerHex for Binvec<L, N> {
+    /// Formats the bits as a hexadecimal number, with the bit at index `L - 1` as the most significant digit.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.fmt_radix(f, 4, "0x", b"0123456789abcdef")
+    }
+}
+
+
+// impl UpperHex
+impl<const L: usize, const N: usize> UpperHex for Binvec<L, N> {
+    /// Formats the bits as a hexadecimal number, with the bit at index `L - 1` as the most significant digit.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.fmt_radix(f, 4, "0x", b"0123456789ABCDEF")
+    }
+}
+
+
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Writes the bits as a number in a power-of-two radix, honoring the formatter flags like the integer impls do.
+    ///
+    /// Every digit up to the length `L` is written, including leading zeros.
+    /// The digits are streamed one by one, so no buffer is needed for long vectors.
+    /// 
+    fn fmt_radix(&self, f: &mut Formatter<'_>, bits_per_digit: usize, prefix: &str, digits: &[u8]) -> Result {
+        let digit_count: usize = if L == 0 { 1 } else { L.div_ceil(bits_per_digit) };
+        let sign: &str = if f.sign_plus() { "+" } else { "" };
+        let prefix: &str = if f.alternate() { prefix } else { "" };
+        let len: usize = sign.len() + prefix.len() + digit_count;
+        let padding: usize = f.width().map_or(0, |width| width.saturating_sub(len));
+
+        let write_digits = |f: &mut Formatter<'_>| -> Result {
+            for digit in (0..digit_count).rev() {
+                let start: usize = digit * bits_per_digit;
+                let len: usize = if L - start < bits_per_digit { L - start } else { bits_per_digit };
+                let value: u64 = if len == 0 { 0 } else { self.load_bits(start, len) };
+                f.write_char(digits[value as usize] as char)?;
+            }
+            Ok(())
+        };
+        let write_fill = |f: &mut Formatter<'_>, fill: char, count: usize| -> Result {
+            for _ in 0..count {
+                f.write_char(fill)?;
+            }
+            Ok(())
+        };
+
+        if f.sign_aware_zero_pad() {
+            f.write_str(sign)?;
+            f.write_str(prefix)?;
+            write_fill(f, '0', padding)?;
+            return write_digits(f);
+        }
+        let (pre, post): (usize, usize) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+        let fill: char = f.fill();
+        write_fill(f, fill, pre)?;
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        write_digits(f)?;
+        write_fill(f, fill, post)
+    }
+}
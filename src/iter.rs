@@ -1,15 +1,18 @@
-use crate::Binvec;
+use core::iter::FusedIterator;
+use crate::{Binvec, BinvecSlice};
 
 
 /// An iterator over a `Binvec` that yields each bit in sequence.
 ///
 /// The iterator maintains an internal index and returns bits one by one
 /// until all bits in the `Binvec` have been iterated over.
+/// It can also be consumed from the back, so `.rev()` yields the bits from the highest index.
 /// 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BinvecIter<'a, const L: usize, const N: usize> {
     binvec: &'a Binvec<L, N>,
     index: usize,
+    end: usize,
 }
 
 
@@ -34,7 +37,7 @@ impl<'a, const L: usize, const N: usize> BinvecIter<'a, L, N> {
     /// ```
     /// 
     pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
-        Self { binvec, index: 0 }
+        Self { binvec, index: 0, end: L }
     }
 }
 
@@ -44,8 +47,8 @@ impl<'a, const L: usize, const N: usize> Iterator for BinvecIter<'a, L, N> {
     type Item = bool;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < L {
-            // SAFETY: index is guaranteed to be less than L
+        if self.index < self.end {
+            // SAFETY: index is guaranteed to be less than end, which is at most L
             let bit: bool = unsafe { self.binvec.get_unchecked(self.index) };
             self.index += 1;
             Some(bit)
@@ -53,4 +56,697 @@ impl<'a, const L: usize, const N: usize> Iterator for BinvecIter<'a, L, N> {
             None
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.end - self.index {
+            self.index += n;
+            self.next()
+        } else {
+            self.index = self.end;
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.index
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+
+// impl DoubleEndedIterator
+impl<'a, const L: usize, const N: usize> DoubleEndedIterator for BinvecIter<'a, L, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            // SAFETY: end is guaranteed to be less than L after decrementing
+            let bit: bool = unsafe { self.binvec.get_unchecked(self.end) };
+            Some(bit)
+        } else {
+            None
+        }
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<'a, const L: usize, const N: usize> ExactSizeIterator for BinvecIter<'a, L, N> {}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecIter<'a, L, N> {}
+
+
+/// An iterator over the indices of the bits set to `1` in a `Binvec`.
+///
+/// The iterator loads 64 bits at a time and jumps between set bits with `trailing_zeros`,
+/// so runs of `0` bits are skipped without visiting them one by one.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecIterOnes<'a, const L: usize, const N: usize> {
+    binvec: &'a Binvec<L, N>,
+    word: u64,
+    word_start: usize,
+    next_byte: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecIterOnes<'a, L, N> {
+    /// Creates a new `BinvecIterOnes` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecIterOnes` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecIterOnes::new(&binvec);
+    /// assert_eq!(iter.next(), Some(0));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        Self { binvec, word: 0, word_start: 0, next_byte: 0 }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecIterOnes<'a, L, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            if self.next_byte >= N {
+                return None;
+            }
+            self.word = self.binvec.load_word(self.next_byte); // unused bits are always 0
+            self.word_start = self.next_byte << 3;
+            self.next_byte += 8;
+        }
+        let offset: usize = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1; // clear the lowest set bit
+        Some(self.word_start + offset)
+    }
+}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecIterOnes<'a, L, N> {}
+
+
+/// An iterator over the indices of the bits set to `0` in a `Binvec`.
+///
+/// The iterator loads 64 bits at a time and jumps between cleared bits with `trailing_zeros`,
+/// so runs of `1` bits are skipped without visiting them one by one.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecIterZeros<'a, const L: usize, const N: usize> {
+    binvec: &'a Binvec<L, N>,
+    word: u64,
+    word_start: usize,
+    next_byte: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecIterZeros<'a, L, N> {
+    /// Creates a new `BinvecIterZeros` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecIterZeros` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, false);
+    /// let mut iter = BinvecIterZeros::new(&binvec);
+    /// assert_eq!(iter.next(), Some(0));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        Self { binvec, word: 0, word_start: 0, next_byte: 0 }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecIterZeros<'a, L, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            if self.next_byte >= N {
+                return None;
+            }
+            self.word = !self.binvec.load_word(self.next_byte);
+            self.word_start = self.next_byte << 3;
+            self.next_byte += 8;
+            let remaining: usize = L - self.word_start;
+            if remaining < 64 {
+                self.word &= (1u64 << remaining) - 1; // the unused bits are 1 after inverting
+            }
+        }
+        let offset: usize = self.word.trailing_zeros() as usize;
+        self.word &= self.word - 1; // clear the lowest set bit
+        Some(self.word_start + offset)
+    }
 }
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecIterZeros<'a, L, N> {}
+
+
+/// An owning iterator over a `Binvec` that yields each bit in sequence.
+///
+/// This is returned by `Binvec::into_iter`, so the iterator can outlive the place
+/// where the `Binvec` was created, for example when returned from a function.
+/// Like [`BinvecIter`], it can be consumed from both ends.
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinvecIntoIter<const L: usize, const N: usize> {
+    binvec: Binvec<L, N>,
+    index: usize,
+    end: usize,
+}
+
+
+impl<const L: usize, const N: usize> BinvecIntoIter<L, N> {
+    /// Creates a new `BinvecIntoIter` that takes ownership of the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: The `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecIntoIter` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = binvec.into_iter();
+    /// assert_eq!(iter.next(), Some(true));
+    /// ```
+    /// 
+    pub const fn new(binvec: Binvec<L, N>) -> Self {
+        Self { binvec, index: 0, end: L }
+    }
+}
+
+
+// impl Iterator
+impl<const L: usize, const N: usize> Iterator for BinvecIntoIter<L, N> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            // SAFETY: index is guaranteed to be less than end, which is at most L
+            let bit: bool = unsafe { self.binvec.get_unchecked(self.index) };
+            self.index += 1;
+            Some(bit)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.end - self.index {
+            self.index += n;
+            self.next()
+        } else {
+            self.index = self.end;
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.index
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+
+// impl DoubleEndedIterator
+impl<const L: usize, const N: usize> DoubleEndedIterator for BinvecIntoIter<L, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            // SAFETY: end is guaranteed to be less than L after decrementing
+            let bit: bool = unsafe { self.binvec.get_unchecked(self.end) };
+            Some(bit)
+        } else {
+            None
+        }
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<const L: usize, const N: usize> ExactSizeIterator for BinvecIntoIter<L, N> {}
+
+
+// impl FusedIterator
+impl<const L: usize, const N: usize> FusedIterator for BinvecIntoIter<L, N> {}
+
+
+/// An iterator over a `Binvec` that yields each bit together with its index.
+///
+/// This behaves like `binvec.iter().enumerate()`, but the index comes straight from the
+/// underlying [`BinvecIter`], so it stays correct when the iterator is consumed from the back.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecIterEnumerated<'a, const L: usize, const N: usize> {
+    iter: BinvecIter<'a, L, N>,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecIterEnumerated<'a, L, N> {
+    /// Creates a new `BinvecIterEnumerated` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecIterEnumerated` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecIterEnumerated::new(&binvec);
+    /// assert_eq!(iter.next(), Some((0, true)));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        Self { iter: BinvecIter::new(binvec) }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecIterEnumerated<'a, L, N> {
+    type Item = (usize, bool);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index: usize = self.iter.index;
+        self.iter.next().map(|bit| (index, bit))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index: usize = self.iter.index.saturating_add(n);
+        self.iter.nth(n).map(|bit| (index, bit))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+
+// impl DoubleEndedIterator
+impl<'a, const L: usize, const N: usize> DoubleEndedIterator for BinvecIterEnumerated<'a, L, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let bit: bool = self.iter.next_back()?;
+        Some((self.iter.end, bit))
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<'a, const L: usize, const N: usize> ExactSizeIterator for BinvecIterEnumerated<'a, L, N> {}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecIterEnumerated<'a, L, N> {}
+
+
+/// An iterator over a `Binvec` that yields the bits in groups of 64 as [`u64`] words.
+///
+/// Bit `i` of each word is the bit at index `word_index * 64 + i`,
+/// and the bits of the final word beyond the length `L` are always `0`.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecIterWords<'a, const L: usize, const N: usize> {
+    binvec: &'a Binvec<L, N>,
+    next_byte: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecIterWords<'a, L, N> {
+    /// Creates a new `BinvecIterWords` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecIterWords` instance starting at the first word.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecIterWords::new(&binvec);
+    /// assert_eq!(iter.next(), Some(0x0FFF));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        Self { binvec, next_byte: 0 }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecIterWords<'a, L, N> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_byte < N {
+            let word: u64 = self.binvec.load_word(self.next_byte); // unused bits are always 0
+            self.next_byte += 8;
+            Some(word)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = N.saturating_sub(self.next_byte).div_ceil(8);
+        (remaining, Some(remaining))
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<'a, const L: usize, const N: usize> ExactSizeIterator for BinvecIterWords<'a, L, N> {}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecIterWords<'a, L, N> {}
+
+
+/// An iterator over every overlapping window of a fixed number of consecutive bits in a `Binvec`.
+///
+/// Each window is yielded as a [`u64`] where bit `i` is the bit at index `start + i`,
+/// so the window starting at index `0` comes first.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecWindows<'a, const L: usize, const N: usize> {
+    binvec: &'a Binvec<L, N>,
+    width: usize,
+    index: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecWindows<'a, L, N> {
+    /// Creates a new `BinvecWindows` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    /// - `width`: The number of bits in each window, from 1 to 64.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecWindows` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Panics
+    /// Panics if `width` is `0` or greater than 64.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecWindows::new(&binvec, 4);
+    /// assert_eq!(iter.next(), Some(0b1111));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>, width: usize) -> Self {
+        assert!(width > 0 && width <= 64, "window width must be between 1 and 64");
+        Self { binvec, width, index: 0 }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecWindows<'a, L, N> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index + self.width <= L {
+            let window: u64 = self.binvec.load_bits(self.index, self.width);
+            self.index += 1;
+            Some(window)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = (L + 1).saturating_sub(self.index + self.width);
+        (remaining, Some(remaining))
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<'a, const L: usize, const N: usize> ExactSizeIterator for BinvecWindows<'a, L, N> {}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecWindows<'a, L, N> {}
+
+
+/// An iterator over the runs of consecutive equal bits in a `Binvec`.
+///
+/// Each run is yielded as a `(value, length)` pair.
+/// The end of a run is found 64 bits at a time with `trailing_zeros`, so long runs are cheap.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecRuns<'a, const L: usize, const N: usize> {
+    binvec: &'a Binvec<L, N>,
+    index: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize> BinvecRuns<'a, L, N> {
+    /// Creates a new `BinvecRuns` for the given `Binvec`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecRuns` instance starting at the first bit.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecRuns::new(&binvec);
+    /// assert_eq!(iter.next(), Some((true, 12)));
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        Self { binvec, index: 0 }
+    }
+}
+
+
+// impl Iterator
+impl<'a, const L: usize, const N: usize> Iterator for BinvecRuns<'a, L, N> {
+    type Item = (bool, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= L {
+            return None;
+        }
+        // SAFETY: index is guaranteed to be less than L
+        let value: bool = unsafe { self.binvec.get_unchecked(self.index) };
+        let start: usize = self.index;
+        while self.index < L {
+            let len: usize = if L - self.index < 64 { L - self.index } else { 64 };
+            let mut word: u64 = self.binvec.load_bits(self.index, len);
+            if value {
+                word = !word;
+            }
+            let run: usize = word.trailing_zeros() as usize;
+            if run < len {
+                self.index += run;
+                break;
+            }
+            self.index += len;
+        }
+        Some((value, self.index - start))
+    }
+}
+
+
+// impl FusedIterator
+impl<'a, const L: usize, const N: usize> FusedIterator for BinvecRuns<'a, L, N> {}
+
+
+/// An iterator over a `BinvecSlice` that yields each bit in sequence.
+///
+/// It can also be consumed from the back, so `.rev()` yields the bits from the end of the view.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinvecSliceIter<'a> {
+    slice: BinvecSlice<'a>,
+    index: usize,
+    end: usize,
+}
+
+
+impl<'a> BinvecSliceIter<'a> {
+    /// Creates a new `BinvecSliceIter` for the given `BinvecSlice`.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `slice`: The `BinvecSlice` to iterate over.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `BinvecSliceIter` instance starting at the first bit of the view.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(12, true);
+    /// let mut iter = BinvecSliceIter::new(binvec.slice(4..8).unwrap());
+    /// assert_eq!(iter.next(), Some(true));
+    /// ```
+    /// 
+    pub const fn new(slice: BinvecSlice<'a>) -> Self {
+        Self { slice, index: 0, end: slice.len() }
+    }
+}
+
+
+// impl Iterator
+impl<'a> Iterator for BinvecSliceIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            let bit: Option<bool> = self.slice.get(self.index);
+            self.index += 1;
+            bit
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining: usize = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.end - self.index {
+            self.index += n;
+            self.next()
+        } else {
+            self.index = self.end;
+            None
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.end - self.index
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+
+// impl DoubleEndedIterator
+impl<'a> DoubleEndedIterator for BinvecSliceIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            self.end -= 1;
+            self.slice.get(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+
+// impl ExactSizeIterator
+impl<'a> ExactSizeIterator for BinvecSliceIter<'a> {}
+
+
+// impl FusedIterator
+impl<'a> FusedIterator for BinvecSliceIter<'a> {}
@@ -0,0 +1,294 @@
+//! Wide loops over packed bytes shared by the counting and bitwise methods.
+//!
+//! The bytes are read as little-endian [`u64`] words, four at a time for the counts.
+//! The remaining bytes at the end are handled one at a time.
+//!
+//! With the `simd` feature on `x86_64`, the non-const entry points (`count_ones_simd`, `count_ones_combined_simd`,
+//! and the `*_assign` operations) switch to AVX2 when the CPU supports it, and use the scalar code otherwise.
+//! The `const fn` kernels cannot detect CPU features and always use the scalar code.
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod avx2;
+
+
+/// The number of bytes processed per iteration of the counting loops.
+const BLOCK: usize = 32;
+
+
+/// Reads 8 bytes starting at `i` as a little-endian word.
+#[inline(always)]
+const fn read_u64(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes([
+        bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3],
+        bytes[i + 4], bytes[i + 5], bytes[i + 6], bytes[i + 7],
+    ])
+}
+
+
+/// Counts the bits set to `1` in `bytes`.
+pub(crate) const fn count_ones(bytes: &[u8]) -> usize {
+    let mut count: usize = 0;
+    let mut i: usize = 0;
+    while i + BLOCK <= bytes.len() {
+        count += (read_u64(bytes, i).count_ones()
+            + read_u64(bytes, i + 8).count_ones()
+            + read_u64(bytes, i + 16).count_ones()
+            + read_u64(bytes, i + 24).count_ones()) as usize;
+        i += BLOCK;
+    }
+    while i < bytes.len() {
+        count += bytes[i].count_ones() as usize;
+        i += 1;
+    }
+    count
+}
+
+
+/// Counts the bits set to `1` in `bytes`, using SIMD when it is enabled and available.
+#[cfg(any(feature = "alloc", feature = "rayon", feature = "simd"))]
+#[inline]
+pub(crate) fn count_ones_simd(bytes: &[u8]) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some(count) = avx2::count_ones(bytes) {
+        return count;
+    }
+    count_ones(bytes)
+}
+
+
+/// Returns `true` if at least `n` bits are set to `1` in `bytes`.
+///
+/// The scan stops as soon as `n` bits are found, or as soon as the bytes left could not reach `n` even if all ones.
+pub(crate) const fn count_ones_reaches(bytes: &[u8], n: usize) -> bool {
+    let mut count: usize = 0;
+    let mut i: usize = 0;
+    while count < n {
+        let remaining: usize = bytes.len() - i;
+        if count + (remaining << 3) < n {
+            return false;
+        }
+        if remaining >= 8 {
+            count += read_u64(bytes, i).count_ones() as usize;
+            i += 8;
+        } else {
+            count += bytes[i].count_ones() as usize;
+            i += 1;
+        }
+    }
+    true
+}
+
+
+/// Returns `true` if an odd number of bits are set to `1` in `bytes`.
+pub(crate) const fn parity(bytes: &[u8]) -> bool {
+    let mut folded: u64 = 0;
+    let mut i: usize = 0;
+    while i + 8 <= bytes.len() {
+        folded ^= read_u64(bytes, i);
+        i += 8;
+    }
+    while i < bytes.len() {
+        folded ^= bytes[i] as u64;
+        i += 1;
+    }
+    folded.count_ones() & 1 != 0
+}
+
+
+/// A bitwise operation between two words, used to count bits of a combination without storing it.
+#[derive(Clone, Copy)]
+pub(crate) enum Combine {
+    And,
+    Or,
+    Xor,
+    AndNot,
+}
+
+
+impl Combine {
+    #[inline(always)]
+    pub(crate) const fn apply(self, a: u64, b: u64) -> u64 {
+        match self {
+            Combine::And => a & b,
+            Combine::Or => a | b,
+            Combine::Xor => a ^ b,
+            Combine::AndNot => a & !b,
+        }
+    }
+}
+
+
+/// Counts the bits set to `1` in `op(a, b)`. Both slices must have the same length.
+pub(crate) const fn count_ones_combined(a: &[u8], b: &[u8], op: Combine) -> usize {
+    let mut count: usize = 0;
+    let mut i: usize = 0;
+    while i + BLOCK <= a.len() {
+        count += (op.apply(read_u64(a, i), read_u64(b, i)).count_ones()
+            + op.apply(read_u64(a, i + 8), read_u64(b, i + 8)).count_ones()
+            + op.apply(read_u64(a, i + 16), read_u64(b, i + 16)).count_ones()
+            + op.apply(read_u64(a, i + 24), read_u64(b, i + 24)).count_ones()) as usize;
+        i += BLOCK;
+    }
+    while i < a.len() {
+        count += (op.apply(a[i] as u64, b[i] as u64) as u8).count_ones() as usize;
+        i += 1;
+    }
+    count
+}
+
+
+/// Counts the bits set to `1` in `op(a, b)`, using SIMD when it is enabled and available.
+/// Both slices must have the same length.
+#[cfg(feature = "simd")]
+#[inline]
+pub(crate) fn count_ones_combined_simd(a: &[u8], b: &[u8], op: Combine) -> usize {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if let Some(count) = avx2::count_ones_combined(a, b, op) {
+        return count;
+    }
+    count_ones_combined(a, b, op)
+}
+
+
+/// Returns `true` if `op(a, b)` has any bit set to `1`, stopping at the first such word.
+/// Both slices must have the same length.
+pub(crate) const fn any_combined(a: &[u8], b: &[u8], op: Combine) -> bool {
+    let mut i: usize = 0;
+    while i + 8 <= a.len() {
+        if op.apply(read_u64(a, i), read_u64(b, i)) != 0 {
+            return true;
+        }
+        i += 8;
+    }
+    while i < a.len() {
+        if op.apply(a[i] as u64, b[i] as u64) as u8 != 0x00 {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+
+/// Returns the index of the first bit set to `1` in `bytes`, or of the first bit set to `0` when `invert` is `true`.
+pub(crate) const fn first_one(bytes: &[u8], invert: bool) -> Option<usize> {
+    let flip: u64 = if invert { u64::MAX } else { 0 };
+    let mut i: usize = 0;
+    while i + 8 <= bytes.len() {
+        let word: u64 = read_u64(bytes, i) ^ flip;
+        if word != 0 {
+            return Some((i << 3) + word.trailing_zeros() as usize);
+        }
+        i += 8;
+    }
+    while i < bytes.len() {
+        let byte: u8 = bytes[i] ^ flip as u8;
+        if byte != 0x00 {
+            return Some((i << 3) + byte.trailing_zeros() as usize);
+        }
+        i += 1;
+    }
+    None
+}
+
+
+/// Returns the index of the last bit set to `1` in `bytes`.
+pub(crate) const fn last_one(bytes: &[u8]) -> Option<usize> {
+    let mut i: usize = bytes.len();
+    while i >= 8 {
+        i -= 8;
+        let word: u64 = read_u64(bytes, i);
+        if word != 0 {
+            return Some((i << 3) + 63 - word.leading_zeros() as usize);
+        }
+    }
+    while i > 0 {
+        i -= 1;
+        if bytes[i] != 0x00 {
+            return Some((i << 3) + 7 - bytes[i].leading_zeros() as usize);
+        }
+    }
+    None
+}
+
+
+/// Counts the bits set to `1` before bit `index` of `bytes`, where `index` is at most `bytes.len() * 8`.
+pub(crate) const fn rank(bytes: &[u8], index: usize) -> usize {
+    let byte_index: usize = index >> 3; // same as `index / 8`
+    let bit_offset: usize = index & 0b111; // same as `index % 8`
+    let mut count: usize = count_ones(bytes.split_at(byte_index).0);
+    if bit_offset > 0 {
+        let mask: u8 = (1u8 << bit_offset) - 1;
+        count += (bytes[byte_index] & mask).count_ones() as usize;
+    }
+    count
+}
+
+
+/// Returns the index of the `n`-th bit set to `1` in `bytes`, counting from `0`.
+pub(crate) const fn select(bytes: &[u8], n: usize) -> Option<usize> {
+    let mut remaining: usize = n;
+    let mut i: usize = 0;
+    while i < bytes.len() {
+        let mut byte: u8 = bytes[i];
+        let ones: usize = byte.count_ones() as usize;
+        if remaining < ones {
+            while remaining > 0 {
+                byte &= byte - 1; // clear the lowest set bit
+                remaining -= 1;
+            }
+            return Some((i << 3) + byte.trailing_zeros() as usize);
+        }
+        remaining -= ones;
+        i += 1;
+    }
+    None
+}
+
+
+/// Applies `op` to every byte of `dst` and the matching byte of `src`, a word at a time or with SIMD when it is enabled and available.
+/// Both slices must have the same length.
+#[inline(always)]
+fn combine(dst: &mut [u8], src: &[u8], op: Combine) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if avx2::combine(dst, src, op) {
+        return;
+    }
+    let mut dst_blocks = dst.chunks_exact_mut(8);
+    let mut src_blocks = src.chunks_exact(8);
+    for (d, s) in (&mut dst_blocks).zip(&mut src_blocks) {
+        let word: u64 = op.apply(read_u64(d, 0), read_u64(s, 0));
+        d.copy_from_slice(&word.to_le_bytes());
+    }
+    for (d, s) in dst_blocks.into_remainder().iter_mut().zip(src_blocks.remainder()) {
+        *d = op.apply(*d as u64, *s as u64) as u8;
+    }
+}
+
+
+/// Performs `dst &= src` byte-wise.
+#[inline]
+pub(crate) fn bitand_assign(dst: &mut [u8], src: &[u8]) {
+    combine(dst, src, Combine::And);
+}
+
+
+/// Performs `dst |= src` byte-wise.
+#[inline]
+pub(crate) fn bitor_assign(dst: &mut [u8], src: &[u8]) {
+    combine(dst, src, Combine::Or);
+}
+
+
+/// Performs `dst ^= src` byte-wise.
+#[inline]
+pub(crate) fn bitxor_assign(dst: &mut [u8], src: &[u8]) {
+    combine(dst, src, Combine::Xor);
+}
+
+
+/// Performs `dst &= !src` byte-wise.
+#[inline]
+pub(crate) fn andnot_assign(dst: &mut [u8], src: &[u8]) {
+    combine(dst, src, Combine::AndNot);
+}
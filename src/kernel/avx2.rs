@@ -0,0 +1,110 @@
+//! AVX2 versions of the kernels, chosen at runtime when the CPU supports them.
+//!
+//! Each entry point checks for AVX2 with [`is_x86_feature_detected!`] and returns `None` or `false`
+//! when it is missing or the input is too short to be worth it, so the caller falls back to the scalar code.
+//! Popcounts use the nibble lookup with `vpshufb` and sum the byte counts with `vpsadbw`, 32 bytes per iteration.
+
+use core::arch::x86_64::*;
+use super::Combine;
+
+
+/// The number of bytes processed per iteration.
+const LANES: usize = 32;
+
+/// Inputs shorter than this take the scalar path, where the detection and setup would cost more than they save.
+const MIN_LEN: usize = 2 * LANES;
+
+
+/// Counts the bits set to `1` in `bytes`, or returns `None` if the scalar path should be used.
+#[inline]
+pub(super) fn count_ones(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() < MIN_LEN
+    || !is_x86_feature_detected!("avx2") {
+        return None;
+    }
+    // SAFETY: AVX2 was detected at runtime
+    Some(unsafe { count_ones_combined_avx2(bytes, bytes, Combine::And) })
+}
+
+
+/// Counts the bits set to `1` in `op(a, b)`, or returns `None` if the scalar path should be used.
+/// Both slices must have the same length.
+#[inline]
+pub(super) fn count_ones_combined(a: &[u8], b: &[u8], op: Combine) -> Option<usize> {
+    if a.len() < MIN_LEN
+    || !is_x86_feature_detected!("avx2") {
+        return None;
+    }
+    // SAFETY: AVX2 was detected at runtime
+    Some(unsafe { count_ones_combined_avx2(a, b, op) })
+}
+
+
+/// Performs `dst = op(dst, src)`, or returns `false` without touching `dst` if the scalar path should be used.
+/// Both slices must have the same length.
+#[inline]
+pub(super) fn combine(dst: &mut [u8], src: &[u8], op: Combine) -> bool {
+    if dst.len() < MIN_LEN
+    || !is_x86_feature_detected!("avx2") {
+        return false;
+    }
+    // SAFETY: AVX2 was detected at runtime
+    unsafe { combine_avx2(dst, src, op) };
+    true
+}
+
+
+#[target_feature(enable = "avx2")]
+fn count_ones_combined_avx2(a: &[u8], b: &[u8], op: Combine) -> usize {
+    let lookup: __m256i = _mm256_setr_epi8(
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+    );
+    let low_mask: __m256i = _mm256_set1_epi8(0x0F);
+    let mut total: __m256i = _mm256_setzero_si256();
+    let a_blocks = a.chunks_exact(LANES);
+    let b_blocks = b.chunks_exact(LANES);
+    let (a_rest, b_rest): (&[u8], &[u8]) = (a_blocks.remainder(), b_blocks.remainder());
+    for (a_block, b_block) in a_blocks.zip(b_blocks) {
+        // SAFETY: both blocks are exactly 32 bytes, and `loadu` has no alignment requirement
+        let word: __m256i = unsafe {
+            apply(op, _mm256_loadu_si256(a_block.as_ptr().cast()), _mm256_loadu_si256(b_block.as_ptr().cast()))
+        };
+        let low: __m256i = _mm256_and_si256(word, low_mask);
+        let high: __m256i = _mm256_and_si256(_mm256_srli_epi16::<4>(word), low_mask);
+        let counts: __m256i = _mm256_add_epi8(_mm256_shuffle_epi8(lookup, low), _mm256_shuffle_epi8(lookup, high));
+        total = _mm256_add_epi64(total, _mm256_sad_epu8(counts, _mm256_setzero_si256())); // each byte count is at most 8, so the sums cannot overflow
+    }
+    let mut lanes: [u64; 4] = [0; 4];
+    // SAFETY: `lanes` is 32 bytes, and `storeu` has no alignment requirement
+    unsafe { _mm256_storeu_si256(lanes.as_mut_ptr().cast(), total) };
+    (lanes[0] + lanes[1] + lanes[2] + lanes[3]) as usize + super::count_ones_combined(a_rest, b_rest, op)
+}
+
+
+#[target_feature(enable = "avx2")]
+fn combine_avx2(dst: &mut [u8], src: &[u8], op: Combine) {
+    let mut dst_blocks = dst.chunks_exact_mut(LANES);
+    let mut src_blocks = src.chunks_exact(LANES);
+    for (d, s) in (&mut dst_blocks).zip(&mut src_blocks) {
+        // SAFETY: both blocks are exactly 32 bytes, and `loadu`/`storeu` have no alignment requirement
+        unsafe {
+            let word: __m256i = apply(op, _mm256_loadu_si256(d.as_ptr().cast()), _mm256_loadu_si256(s.as_ptr().cast()));
+            _mm256_storeu_si256(d.as_mut_ptr().cast(), word);
+        }
+    }
+    for (d, s) in dst_blocks.into_remainder().iter_mut().zip(src_blocks.remainder()) {
+        *d = op.apply(*d as u64, *s as u64) as u8;
+    }
+}
+
+
+#[target_feature(enable = "avx2")]
+fn apply(op: Combine, a: __m256i, b: __m256i) -> __m256i {
+    match op {
+        Combine::And => _mm256_and_si256(a, b),
+        Combine::Or => _mm256_or_si256(a, b),
+        Combine::Xor => _mm256_xor_si256(a, b),
+        Combine::AndNot => _mm256_andnot_si256(b, a), // `vpandn` negates its first operand
+    }
+}
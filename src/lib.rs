@@ -1,8 +1,94 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod error;
 
 mod iter;
 pub use iter::*;
 
+mod ops;
+
+mod search;
+
+mod count;
+
+mod range;
+
+mod convert;
+
+mod fmt;
+
+mod kernel;
+
+mod allocator;
+
+mod set;
+
+mod arith;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "base64")]
+mod base64_impl;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+
+#[cfg(feature = "rand")]
+mod rand_impl;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+
+#[cfg(feature = "proptest")]
+mod proptest_impl;
+
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+
+#[cfg(feature = "simd")]
+mod simd_impl;
+
+mod rank_select;
+pub use rank_select::*;
+
+mod bit_ref;
+pub use bit_ref::*;
+
+mod slice;
+pub use slice::*;
+
+mod slice_mut;
+pub use slice_mut::*;
+
+#[cfg(target_has_atomic = "8")]
+mod atomic;
+#[cfg(target_has_atomic = "8")]
+pub use atomic::*;
+
+mod bloom;
+pub use bloom::*;
+
+mod binmat;
+pub use binmat::*;
+
+mod builder;
+pub use builder::*;
+
+#[cfg(feature = "alloc")]
+mod binvec_dyn;
+#[cfg(feature = "alloc")]
+pub use binvec_dyn::*;
+
+#[cfg(feature = "alloc")]
+mod small_binvec;
+#[cfg(feature = "alloc")]
+pub use small_binvec::*;
+
 
 /// A fixed-length array type that stores `0` or `1`.
 /// It uses up to 8 times less memory compared to a [`bool`] array.
@@ -27,10 +113,17 @@ pub use iter::*;
 ///       10 bits used       Not used
 /// ```
 /// 
+/// The type is `#[repr(transparent)]` over `[u8; N]`, so it has exactly the layout of its packed bytes.
+/// Casts from external memory are checked so that the unused bits are always `0`:
+/// use [`Binvec::from_bytes`] or [`Binvec::from_bytes_ref`], or `bytemuck::checked` with the `bytemuck` feature.
+///
+/// Because the unused bits are always `0`, equality and [`Hash`] work directly on the packed bytes,
+/// so a [`Binvec`] can be used as a `HashMap` or `HashSet` key.
+/// 
 /// ---
 /// # Generics
 /// - `L`: The number of bits to store.
-/// - `N`: The minimum byte array length required to store `L`.
+/// - `N`: The minimum byte array length required to store `L`. Any other value is rejected at compile time.
 /// 
 /// ---
 /// # Examples
@@ -41,7 +134,9 @@ pub use iter::*;
 /// let mut binvec = binvec!(12, false);
 /// ```
 /// 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable))]
+#[repr(transparent)]
 pub struct Binvec<const L: usize, const N: usize> {
     inner: [u8; N],
 }
@@ -72,7 +167,7 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
     #[deprecated(note = "Use the `binvec!` macro instead.")]
     #[doc(hidden)]
     pub const fn new(initial_value: bool) -> Self {
-        let mut new: Binvec<L, N> = Self { inner: [0x00; N] };
+        let mut new: Binvec<L, N> = Self::from_inner([0x00; N]);
         new.fill(initial_value);
         new
     }
@@ -97,6 +192,26 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
         L
     }
 
+    /// Returns `true` if the [`Binvec`] stores no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length `L` is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, false);
+    /// assert_eq!(binvec.is_empty(), false);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        L == 0
+    }
+
     /// Returns the bit value at the given index without performing bounds checking.
     ///
     /// ---
@@ -159,6 +274,88 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
         }
     }
 
+    /// Returns the bit values at several indices with a single bounds check.
+    ///
+    /// All indices are validated before any bit is read, so the reads themselves skip the per-index check.
+    ///
+    /// ---
+    /// # Generics
+    /// - `K`: The number of indices to read.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `indices`: The bit indices to retrieve, in any order and possibly repeated.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok([bool; K])` with the bit at `indices[i]` in position `i`.
+    /// - `Err(IndexOutOfBounds)` if any index is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = Binvec::<12, 2>::from_raw_bytes([0b0010_0001, 0b0000]);
+    /// assert_eq!(binvec.get_many(&[5, 0, 1]), Ok([true, true, false]));
+    /// assert_eq!(binvec.get_many(&[5, 20]), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    pub const fn get_many<const K: usize>(&self, indices: &[usize; K]) -> Result<[bool; K], error::IndexOutOfBounds> {
+        let mut max: usize = 0;
+        let mut i: usize = 0;
+        while i < K {
+            max = if indices[i] > max { indices[i] } else { max };
+            i += 1;
+        }
+        if K != 0 && max >= L {
+            return Err(error::IndexOutOfBounds);
+        }
+        let mut bits: [bool; K] = [false; K];
+        let mut i: usize = 0;
+        while i < K {
+            bits[i] = unsafe { self.get_unchecked(indices[i]) }; // every index was checked above
+            i += 1;
+        }
+        Ok(bits)
+    }
+
+    /// Returns a mutable reference proxy to the bit at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to reference.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(BitRefMut)` that writes its value back when dropped.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// if let Some(mut bit) = binvec.get_mut(5) {
+    ///     assert_eq!(*bit, false);
+    ///     *bit = true;
+    /// }
+    /// assert_eq!(binvec.get(5), Some(true));
+    /// assert!(binvec.get_mut(20).is_none());
+    /// ```
+    /// 
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<BitRefMut<'_>> {
+        if index < L {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(BitRefMut::new(&mut self.inner[byte_index], bit_offset))
+        } else {
+            None
+        }
+    }
+
     /// Sets the bit value at the given index without performing bounds checking.
     ///
     /// ---
@@ -185,7 +382,7 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
         let bit_offset: usize = index & 0b111; // same as `index % 8`
         let mask: u8 = 1 << bit_offset;
         let byte: &mut u8 = &mut self.inner[byte_index];
-        if value == true {
+        if value {
             *byte |= mask;
         } else {
             *byte &= !mask;
@@ -226,14 +423,19 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
         }
     }
 
-    /// Fills the entire [`Binvec`] with the specified bit value.
+    /// Sets the bits at several indices to the same value.
     ///
-    /// This method sets all bits in the [`Binvec`] to either `true` (1) or `false` (0).
-    /// For bits beyond the length `L` in the last byte, those bits are cleared to zero.
+    /// All indices are validated before any bit is written, so either every bit is set or none is.
     ///
     /// ---
     /// # Arguments
-    /// - `value`: The bit value to fill the [`Binvec`] with (`true` for 1, `false` for 0).
+    /// - `indices`: The bit indices to modify, in any order and possibly repeated.
+    /// - `value`: The bit value to write (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if every bit was set.
+    /// - `Err(IndexOutOfBounds)` if any index is out of bounds. The [`Binvec`] is left unchanged.
     ///
     /// ---
     /// # Examples
@@ -241,175 +443,1110 @@ impl<const L: usize, const N: usize> Binvec<L, N> {
     /// use binvec::*;
     ///
     /// let mut binvec = binvec!(12, false);
-    /// binvec.fill(true);
-    /// assert_eq!(binvec.is_all_one(), true);
+    /// assert_eq!(binvec.set_many(&[0, 5, 11], true), Ok(()));
+    /// assert_eq!(binvec.count_ones(), 3);
+    ///
+    /// assert_eq!(binvec.set_many(&[1, 20], true), Err(error::IndexOutOfBounds));
+    /// assert_eq!(binvec.get(1), Some(false));
     /// ```
     /// 
-    pub const fn fill(&mut self, value: bool) {
-        let byte: u8 = if value == true { 0xFF } else { 0x00 };
-        let mut inner: [u8; N] = [byte; N];
-        if L > 0
-        && L % 8 != 0 {
-            let last_bits: usize = L % 8;
-            let mask: u8 = (1u8 << last_bits) - 1;
-            inner[N - 1] &= mask;
+    pub const fn set_many(&mut self, indices: &[usize], value: bool) -> Result<(), error::IndexOutOfBounds> {
+        let mut i: usize = 0;
+        while i < indices.len() {
+            if indices[i] >= L {
+                return Err(error::IndexOutOfBounds);
+            }
+            i += 1;
+        }
+        let mut i: usize = 0;
+        while i < indices.len() {
+            let byte_index: usize = indices[i] >> 3; // same as `index / 8`
+            let mask: u8 = 1 << (indices[i] & 0b111);
+            if value {
+                self.inner[byte_index] |= mask;
+            } else {
+                self.inner[byte_index] &= !mask;
+            }
+            i += 1;
         }
-        self.inner = inner;
+        Ok(())
     }
 
-    /// Counts the number of bits set to `1` in the [`Binvec`].
+    /// Sets the bits at several indices, each to its own value.
+    ///
+    /// All indices are validated before any bit is written, so either every pair is applied or none is.
+    /// Pairs are applied in order, so a later pair wins over an earlier one with the same index.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `pairs`: The `(index, value)` pairs to write.
     ///
     /// ---
     /// # Returns
-    /// The total count of bits that are set to `1`.
+    /// - `Ok(())` if every pair was applied.
+    /// - `Err(IndexOutOfBounds)` if any index is out of bounds. The [`Binvec`] is left unchanged.
     ///
     /// ---
     /// # Examples
     /// ```
     /// use binvec::*;
     ///
-    /// let binvec = binvec!(12, true);
-    /// assert_eq!(binvec.count_ones(), 12);
-    ///
     /// let mut binvec = binvec!(12, false);
-    /// binvec.set(3, true).unwrap();
-    /// assert_eq!(binvec.count_ones(), 1);
+    /// assert_eq!(binvec.set_from_pairs(&[(0, true), (5, true), (0, false)]), Ok(()));
+    /// assert_eq!(binvec.get(0), Some(false));
+    /// assert_eq!(binvec.get(5), Some(true));
+    ///
+    /// assert_eq!(binvec.set_from_pairs(&[(1, true), (20, true)]), Err(error::IndexOutOfBounds));
+    /// assert_eq!(binvec.get(1), Some(false));
     /// ```
     /// 
-    pub const fn count_ones(&self) -> usize {
-        let mut count: usize = 0;
+    pub const fn set_from_pairs(&mut self, pairs: &[(usize, bool)]) -> Result<(), error::IndexOutOfBounds> {
         let mut i: usize = 0;
-        while i < N {
-            count += self.inner[i].count_ones() as usize;
+        while i < pairs.len() {
+            if pairs[i].0 >= L {
+                return Err(error::IndexOutOfBounds);
+            }
+            i += 1;
+        }
+        let mut i: usize = 0;
+        while i < pairs.len() {
+            let (index, value) = pairs[i];
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            self.inner[byte_index] = (self.inner[byte_index] & !(1 << bit_offset)) | ((value as u8) << bit_offset);
             i += 1;
         }
-        count // unused value is always filled with 0
+        Ok(())
     }
 
-    /// Counts the number of bits set to `0` in the [`Binvec`].
+    /// Flips the bit value at the given index without performing bounds checking.
     ///
     /// ---
-    /// # Returns
-    /// The total count of bits that are set to `0`.
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to flip.
     ///
     /// ---
     /// # Examples
     /// ```
     /// use binvec::*;
     ///
-    /// let binvec = binvec!(12, false);
-    /// assert_eq!(binvec.count_zeros(), 12);
-    ///
-    /// let mut binvec = binvec!(12, true);
-    /// binvec.set(3, false).unwrap();
-    /// assert_eq!(binvec.count_zeros(), 1);
+    /// let mut binvec = binvec!(12, false);
+    /// unsafe { binvec.toggle_unchecked(3); }
+    /// assert_eq!(binvec.get(3), Some(true));
     /// ```
     /// 
-    pub const fn count_zeros(&self) -> usize {
-        let mut count: usize = 0;
-        let mut i: usize = 0;
-        while i < N {
-            count += self.inner[i].count_zeros() as usize;
-            i += 1;
-        }
-        count - ((N * 8) - L) // unused value is always filled with 0
+    pub const unsafe fn toggle_unchecked(&mut self, index: usize) {
+        let byte_index: usize = index >> 3; // same as `index / 8`
+        let bit_offset: usize = index & 0b111; // same as `index % 8`
+        self.inner[byte_index] ^= 1 << bit_offset;
     }
 
-    /// Checks if all bits in the [`Binvec`] are set to `1`.
+    /// Flips the bit value at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to flip.
     ///
     /// ---
     /// # Returns
-    /// `true` if all bits that are using is `1`, otherwise `false`.
+    /// - `Ok(())` if the bit was successfully flipped.
+    /// - `Err(IndexOutOfBounds)` if the index is out of bounds.
     ///
     /// ---
     /// # Examples
     /// ```
     /// use binvec::*;
     ///
-    /// let binvec = binvec!(12, true);
-    /// assert_eq!(binvec.is_all_one(), true);
-    ///
     /// let mut binvec = binvec!(12, false);
-    /// assert_eq!(binvec.is_all_one(), false);
-    /// binvec.fill(true);
-    /// assert_eq!(binvec.is_all_one(), true);
+    /// assert_eq!(binvec.toggle(5), Ok(()));
+    /// assert_eq!(binvec.get(5), Some(true));
+    /// assert_eq!(binvec.toggle(5), Ok(()));
+    /// assert_eq!(binvec.get(5), Some(false));
+    ///
+    /// assert_eq!(binvec.toggle(20), Err(error::IndexOutOfBounds));
     /// ```
     /// 
-    #[inline(always)]
-    pub const fn is_all_one(&self) -> bool {
-        self.count_ones() == L
+    #[inline]
+    pub fn toggle(&mut self, index: usize) -> Result<(), error::IndexOutOfBounds> {
+        if index < L {
+            unsafe { self.toggle_unchecked(index); }
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
     }
 
-    /// Checks if all bits in the [`Binvec`] are set to `0`.
+    /// Sets the bit value at the given index and returns the previous value.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to set.
+    /// - `value`: The bit value to set (`true` for 1, `false` for 0).
     ///
     /// ---
     /// # Returns
-    /// `true` if all bits that are using is `0`, otherwise `false`.
+    /// - `Ok(previous)` with the bit value that was stored before.
+    /// - `Err(IndexOutOfBounds)` if the index is out of bounds.
     ///
     /// ---
     /// # Examples
     /// ```
     /// use binvec::*;
     ///
-    /// let binvec = binvec!(12, false);
-    /// assert_eq!(binvec.is_all_zero(), true);
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.replace(5, true), Ok(false));
+    /// assert_eq!(binvec.replace(5, true), Ok(true));
+    /// assert_eq!(binvec.get(5), Some(true));
     ///
-    /// let mut binvec = binvec!(12, true);
-    /// assert_eq!(binvec.is_all_zero(), false);
-    /// binvec.fill(false);
-    /// assert_eq!(binvec.is_all_zero(), true);
+    /// assert_eq!(binvec.replace(20, true), Err(error::IndexOutOfBounds));
     /// ```
     /// 
-    #[inline(always)]
-    pub const fn is_all_zero(&self) -> bool {
-        self.count_zeros() == L
+    #[inline]
+    pub fn replace(&mut self, index: usize, value: bool) -> Result<bool, error::IndexOutOfBounds> {
+        if index < L {
+            let previous: bool = unsafe { self.get_unchecked(index) };
+            unsafe { self.set_unchecked(index, value); }
+            Ok(previous)
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
     }
 
-    /// Returns an iterator over the bits of the [`Binvec`].
+    /// Swaps the bit values at two indices.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `a`: The first bit index.
+    /// - `b`: The second bit index.
     ///
     /// ---
     /// # Returns
-    /// A [`BinvecIter`] that yields each bit as a `bool`.
+    /// - `Ok(())` if the bits were successfully swapped.
+    /// - `Err(IndexOutOfBounds)` if either index is out of bounds. The [`Binvec`] is left unchanged.
     ///
     /// ---
     /// # Examples
     /// ```
     /// use binvec::*;
     ///
-    /// let binvec = binvec!(12, true);
-    /// for bit in binvec.iter() {
-    ///     assert_eq!(bit, true);
-    /// }
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// assert_eq!(binvec.swap(2, 9), Ok(()));
+    /// assert_eq!(binvec.get(2), Some(false));
+    /// assert_eq!(binvec.get(9), Some(true));
+    ///
+    /// assert_eq!(binvec.swap(2, 20), Err(error::IndexOutOfBounds));
     /// ```
     /// 
-    #[inline(always)]
-    pub fn iter(&self) -> BinvecIter<'_, L, N> {
-        BinvecIter::new(self)
+    #[inline]
+    pub fn swap(&mut self, a: usize, b: usize) -> Result<(), error::IndexOutOfBounds> {
+        if a < L
+        && b < L {
+            let bit_a: bool = unsafe { self.get_unchecked(a) };
+            let bit_b: bool = unsafe { self.get_unchecked(b) };
+            if bit_a != bit_b {
+                unsafe {
+                    self.toggle_unchecked(a);
+                    self.toggle_unchecked(b);
+                }
+            }
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
     }
-}
-
-
-// impl IntoIterator
-impl<'a, const L: usize, const N: usize> IntoIterator for &'a Binvec<L, N> {
-    type Item = bool;
-    type IntoIter = BinvecIter<'a, L, N>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Fills the entire [`Binvec`] with the specified bit value.
+    ///
+    /// This method sets all bits in the [`Binvec`] to either `true` (1) or `false` (0).
+    /// For bits beyond the length `L` in the last byte, those bits are cleared to zero.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to fill the [`Binvec`] with (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.fill(true);
+    /// assert_eq!(binvec.is_all_one(), true);
+    /// ```
+    /// 
+    pub const fn fill(&mut self, value: bool) {
+        let byte: u8 = if value { 0xFF } else { 0x00 };
+        self.inner = [byte; N];
+        self.clear_unused_bits();
     }
-}
 
-
-/// Creates a new [`Binvec`].
+    /// Sets the bits where `mask` has a 1 to the specified value, leaving the others unchanged.
+    ///
+    /// This is the same as `self |= mask` for `true` and `self &= !mask` for `false`,
+    /// done a whole word at a time.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `mask`: The [`Binvec`] whose set bits select the bits to write.
+    /// - `value`: The bit value to write (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mask = Binvec::<12, 2>::from_raw_bytes([0b0000_0110, 0b0001]);
+    /// let mut binvec = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0b0000]);
+    /// binvec.set_where(&mask, true);
+    /// assert_eq!(binvec.as_bytes(), &[0b0000_0111, 0b0001]);
+    /// binvec.set_where(&mask, false);
+    /// assert_eq!(binvec.as_bytes(), &[0b0000_0001, 0b0000]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn set_where(&mut self, mask: &Self, value: bool) {
+        if value {
+            kernel::bitor_assign(&mut self.inner, &mask.inner); // unused bits stay 0 because they are 0 in `mask`
+        } else {
+            kernel::andnot_assign(&mut self.inner, &mask.inner);
+        }
+    }
+
+    /// Inverts every bit of the [`Binvec`] in place.
+    ///
+    /// The bits are flipped a whole byte at a time,
+    /// and the unused bits beyond the length `L` are kept cleared to zero.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// binvec.flip_all();
+    /// assert_eq!(binvec.get(3), Some(false));
+    /// assert_eq!(binvec.count_ones(), 11);
+    /// ```
+    /// 
+    pub const fn flip_all(&mut self) {
+        let mut i: usize = 0;
+        while i < N {
+            self.inner[i] = !self.inner[i];
+            i += 1;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Rewrites every bit of the [`Binvec`] with the value returned by a closure.
+    ///
+    /// The closure is called once per bit in ascending index order,
+    /// and each byte is written back once after its 8 bits have been computed.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `f`: A closure that receives the index and current value of a bit and returns its new value.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(1, true).unwrap();
+    /// binvec.map_in_place(|index, bit| bit || index % 4 == 0);
+    /// assert_eq!(binvec.count_ones(), 4);
+    /// assert_eq!(binvec.get(1), Some(true));
+    /// assert_eq!(binvec.get(8), Some(true));
+    /// ```
+    /// 
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, bool) -> bool,
+    {
+        for (byte_index, byte) in self.inner.iter_mut().enumerate() {
+            let start: usize = byte_index << 3;
+            let bits: usize = if L - start < 8 { L - start } else { 8 };
+            let mut mapped: u8 = 0x00;
+            for bit_offset in 0..bits {
+                if f(start + bit_offset, ((*byte >> bit_offset) & 1) != 0) {
+                    mapped |= 1 << bit_offset;
+                }
+            }
+            *byte = mapped;
+        }
+    }
+
+    /// Reverses the order of the bits in the [`Binvec`] in place.
+    ///
+    /// After calling this method, the bit at index `i` holds the value previously stored at index `L - 1 - i`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(0, true).unwrap();
+    /// binvec.set(3, true).unwrap();
+    /// binvec.reverse();
+    /// assert_eq!(binvec.get(11), Some(true));
+    /// assert_eq!(binvec.get(8), Some(true));
+    /// assert_eq!(binvec.count_ones(), 2);
+    /// ```
+    /// 
+    pub const fn reverse(&mut self) {
+        // reverse the whole byte array bit by bit
+        let mut i: usize = 0;
+        while i < N / 2 {
+            let front: u8 = self.inner[i].reverse_bits();
+            self.inner[i] = self.inner[N - 1 - i].reverse_bits();
+            self.inner[N - 1 - i] = front;
+            i += 1;
+        }
+        if N % 2 == 1 {
+            self.inner[N / 2] = self.inner[N / 2].reverse_bits();
+        }
+
+        // the unused bits are now at the front, so move every bit back by their count
+        let unused: usize = (N * 8) - L;
+        if unused > 0 {
+            let mut i: usize = 0;
+            while i < N {
+                let next: u8 = if i + 1 < N { self.inner[i + 1] } else { 0x00 };
+                self.inner[i] = (self.inner[i] >> unused) | (next << (8 - unused));
+                i += 1;
+            }
+        }
+    }
+
+    /// Rotates the bits of the [`Binvec`] toward higher indices by `n` places, wrapping around.
+    ///
+    /// After calling this method, the bit at index `(i + n) % L` holds the value previously stored at index `i`.
+    /// This matches [`u8::rotate_left`] when the bit at index `0` is seen as the least significant bit.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The number of places to rotate by. Values greater than or equal to `L` wrap around.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(10, true).unwrap();
+    /// binvec.rotate_left(3);
+    /// assert_eq!(binvec.get(1), Some(true));
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub const fn rotate_left(&mut self, n: usize) {
+        if L == 0 {
+            return;
+        }
+        let n: usize = n % L;
+        if n == 0 {
+            return;
+        }
+        let mut wrapped: Binvec<L, N> = Self::from_inner(self.inner);
+        wrapped.shift_right(L - n);
+        self.shift_left(n);
+        let mut i: usize = 0;
+        while i < N {
+            self.inner[i] |= wrapped.inner[i];
+            i += 1;
+        }
+    }
+
+    /// Rotates the bits of the [`Binvec`] toward lower indices by `n` places, wrapping around.
+    ///
+    /// After calling this method, the bit at index `i` holds the value previously stored at index `(i + n) % L`.
+    /// This matches [`u8::rotate_right`] when the bit at index `0` is seen as the least significant bit.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The number of places to rotate by. Values greater than or equal to `L` wrap around.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(1, true).unwrap();
+    /// binvec.rotate_right(3);
+    /// assert_eq!(binvec.get(10), Some(true));
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn rotate_right(&mut self, n: usize) {
+        if L == 0 {
+            return;
+        }
+        self.rotate_left(L - (n % L));
+    }
+
+    /// Shifts the bits of the [`Binvec`] toward higher indices by `n` places, filling the vacated bits with zeros.
+    ///
+    /// After calling this method, the bit at index `i + n` holds the value previously stored at index `i`,
+    /// and the bits shifted past the length `L` are discarded.
+    /// This matches `<<` on integers when the bit at index `0` is seen as the least significant bit.
+    /// Whole bytes are moved at once when `n` is a multiple of 8.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The number of places to shift by. Shifting by `L` or more clears every bit.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(1, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// binvec.shift_left(3);
+    /// assert_eq!(binvec.get(4), Some(true));
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub const fn shift_left(&mut self, n: usize) {
+        if n >= L {
+            self.inner = [0x00; N];
+            return;
+        }
+        let byte_shift: usize = n >> 3; // same as `n / 8`
+        let bit_shift: usize = n & 0b111; // same as `n % 8`
+        let mut i: usize = N;
+        while i > 0 {
+            i -= 1;
+            let mut byte: u8 = 0x00;
+            if i >= byte_shift {
+                let src: usize = i - byte_shift;
+                byte = self.inner[src] << bit_shift;
+                if bit_shift > 0
+                && src > 0 {
+                    byte |= self.inner[src - 1] >> (8 - bit_shift);
+                }
+            }
+            self.inner[i] = byte;
+        }
+        self.clear_unused_bits();
+    }
+
+    /// Shifts the bits of the [`Binvec`] toward lower indices by `n` places, filling the vacated bits with zeros.
+    ///
+    /// After calling this method, the bit at index `i` holds the value previously stored at index `i + n`,
+    /// and the bits shifted below index `0` are discarded.
+    /// This matches `>>` on unsigned integers when the bit at index `0` is seen as the least significant bit.
+    /// Whole bytes are moved at once when `n` is a multiple of 8.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The number of places to shift by. Shifting by `L` or more clears every bit.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(1, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// binvec.shift_right(3);
+    /// assert_eq!(binvec.get(7), Some(true));
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub const fn shift_right(&mut self, n: usize) {
+        if n >= L {
+            self.inner = [0x00; N];
+            return;
+        }
+        let byte_shift: usize = n >> 3; // same as `n / 8`
+        let bit_shift: usize = n & 0b111; // same as `n % 8`
+        let mut i: usize = 0;
+        while i < N {
+            let mut byte: u8 = 0x00;
+            let src: usize = i + byte_shift;
+            if src < N {
+                byte = self.inner[src] >> bit_shift;
+                if bit_shift > 0
+                && src + 1 < N {
+                    byte |= self.inner[src + 1] << (8 - bit_shift);
+                }
+            }
+            self.inner[i] = byte;
+            i += 1;
+        }
+    }
+
+    /// Counts the number of bits set to `1` in the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.count_ones(), 12);
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub const fn count_ones(&self) -> usize {
+        kernel::count_ones(&self.inner) // unused value is always filled with 0
+    }
+
+    /// Counts the number of bits set to `0` in the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, false);
+    /// assert_eq!(binvec.count_zeros(), 12);
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// binvec.set(3, false).unwrap();
+    /// assert_eq!(binvec.count_zeros(), 1);
+    /// ```
+    /// 
+    pub const fn count_zeros(&self) -> usize {
+        L - self.count_ones()
+    }
+
+    /// Checks if all bits in the [`Binvec`] are set to `1`.
+    ///
+    /// The scan stops at the first bit set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if all bits that are using is `1`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.is_all_one(), true);
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.is_all_one(), false);
+    /// binvec.fill(true);
+    /// assert_eq!(binvec.is_all_one(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_all_one(&self) -> bool {
+        self.first_zero().is_none()
+    }
+
+    /// Checks if all bits in the [`Binvec`] are set to `0`.
+    ///
+    /// The scan stops at the first bit set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if all bits that are using is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, false);
+    /// assert_eq!(binvec.is_all_zero(), true);
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// assert_eq!(binvec.is_all_zero(), false);
+    /// binvec.fill(false);
+    /// assert_eq!(binvec.is_all_zero(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_all_zero(&self) -> bool {
+        self.first_one().is_none()
+    }
+
+    /// Checks if any bit in the [`Binvec`] is set to `1`.
+    ///
+    /// The bytes are scanned a word at a time and the scan stops at the first bit set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if at least one bit is `1`, otherwise `false`. An empty [`Binvec`] gives `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.any(), false);
+    /// binvec.set(11, true).unwrap();
+    /// assert_eq!(binvec.any(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn any(&self) -> bool {
+        self.first_one().is_some()
+    }
+
+    /// Checks if every bit in the [`Binvec`] is set to `1`.
+    ///
+    /// This is the same as [`is_all_one`](Binvec::is_all_one) and stops at the first bit set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if no bit is `0`, otherwise `false`. An empty [`Binvec`] gives `true`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(binvec!(12, true).all(), true);
+    /// assert_eq!(binvec!(12, false).all(), false);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn all(&self) -> bool {
+        self.is_all_one()
+    }
+
+    /// Checks if no bit in the [`Binvec`] is set to `1`.
+    ///
+    /// This is the same as [`is_all_zero`](Binvec::is_all_zero) and stops at the first bit set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if every bit is `0`, otherwise `false`. An empty [`Binvec`] gives `true`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(binvec!(12, false).none(), true);
+    /// assert_eq!(binvec!(12, true).none(), false);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn none(&self) -> bool {
+        self.is_all_zero()
+    }
+
+    /// Compares two [`Binvec`]s in constant time.
+    ///
+    /// Every byte is compared without an early exit, so the running time does not depend on
+    /// where the first difference is. Use this instead of `==` when the bits are derived from secrets.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare with.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if every bit is equal, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = binvec!(12, true);
+    /// let mut b = binvec!(12, true);
+    /// assert_eq!(a.ct_eq(&b), true);
+    /// b.set(0, false).unwrap();
+    /// assert_eq!(a.ct_eq(&b), false);
+    /// ```
+    /// 
+    #[inline(never)]
+    pub const fn ct_eq(&self, other: &Self) -> bool {
+        let mut diff: u8 = 0x00;
+        let mut i: usize = 0;
+        while i < N {
+            diff |= self.inner[i] ^ other.inner[i];
+            i += 1;
+        }
+        core::hint::black_box(diff) == 0x00 // keep the optimizer from adding an early exit
+    }
+
+    /// Returns an iterator over the bits of the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecIter`] that yields each bit as a `bool`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// for bit in binvec.iter() {
+    ///     assert_eq!(bit, true);
+    /// }
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter(&self) -> BinvecIter<'_, L, N> {
+        BinvecIter::new(self)
+    }
+
+    /// Returns an iterator over the bits of the [`Binvec`] paired with their indices.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecIterEnumerated`] that yields each bit as an `(index, bool)` pair.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// for (index, bit) in binvec.iter_enumerated() {
+    ///     assert_eq!(bit, index == 3);
+    /// }
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter_enumerated(&self) -> BinvecIterEnumerated<'_, L, N> {
+        BinvecIterEnumerated::new(self)
+    }
+
+    /// Returns an iterator over the bits of the [`Binvec`] in groups of 8 as [`u8`] bytes.
+    ///
+    /// Bit `i` of each byte is the bit at index `byte_index * 8 + i`,
+    /// and the bits of the final byte beyond the length `L` are always `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// An iterator that yields `(L + 7) / 8` bytes.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// let mut bytes = binvec.iter_bytes();
+    /// assert_eq!(bytes.next(), Some(0xFF));
+    /// assert_eq!(bytes.next(), Some(0x0F));
+    /// assert_eq!(bytes.next(), None);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter_bytes(&self) -> core::iter::Copied<core::slice::Iter<'_, u8>> {
+        self.inner.iter().copied()
+    }
+
+    /// Returns an iterator over the bits of the [`Binvec`] in groups of 64 as [`u64`] words.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecIterWords`] that yields `(L + 63) / 64` words.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(70, true);
+    /// let mut words = binvec.iter_words();
+    /// assert_eq!(words.next(), Some(u64::MAX));
+    /// assert_eq!(words.next(), Some(0b111111));
+    /// assert_eq!(words.next(), None);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter_words(&self) -> BinvecIterWords<'_, L, N> {
+        BinvecIterWords::new(self)
+    }
+
+    /// Returns an iterator over every overlapping window of `width` consecutive bits.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `width`: The number of bits in each window, from 1 to 64.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecWindows`] that yields `L - width + 1` windows as [`u64`] values,
+    /// or nothing if `width` is greater than `L`.
+    ///
+    /// ---
+    /// # Panics
+    /// Panics if `width` is `0` or greater than 64.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(5, false);
+    /// binvec.set(1, true).unwrap();
+    /// binvec.set(2, true).unwrap();
+    /// let windows: Vec<u64> = binvec.windows(3).collect();
+    /// assert_eq!(windows, [0b110, 0b011, 0b001]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn windows(&self, width: usize) -> BinvecWindows<'_, L, N> {
+        BinvecWindows::new(self, width)
+    }
+
+    /// Returns an iterator over the runs of consecutive equal bits.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecRuns`] that yields each run as a `(value, length)` pair, starting from index `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(6, false);
+    /// binvec.set(0, true).unwrap();
+    /// binvec.set(1, true).unwrap();
+    /// binvec.set(5, true).unwrap();
+    /// let runs: Vec<(bool, usize)> = binvec.runs().collect();
+    /// assert_eq!(runs, [(true, 2), (false, 3), (true, 1)]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn runs(&self) -> BinvecRuns<'_, L, N> {
+        BinvecRuns::new(self)
+    }
+
+    /// Returns an iterator over the indices of the bits set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecIterOnes`] that yields each index in ascending order.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// binvec.set(9, true).unwrap();
+    /// let mut ones = binvec.iter_ones();
+    /// assert_eq!(ones.next(), Some(3));
+    /// assert_eq!(ones.next(), Some(9));
+    /// assert_eq!(ones.next(), None);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter_ones(&self) -> BinvecIterOnes<'_, L, N> {
+        BinvecIterOnes::new(self)
+    }
+
+    /// Returns an iterator over the indices of the bits set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecIterZeros`] that yields each index in ascending order.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// binvec.set(3, false).unwrap();
+    /// binvec.set(9, false).unwrap();
+    /// let mut zeros = binvec.iter_zeros();
+    /// assert_eq!(zeros.next(), Some(3));
+    /// assert_eq!(zeros.next(), Some(9));
+    /// assert_eq!(zeros.next(), None);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub fn iter_zeros(&self) -> BinvecIterZeros<'_, L, N> {
+        BinvecIterZeros::new(self)
+    }
+
+    /// Loads up to 8 bytes starting at `byte_index` as a little-endian word.
+    ///
+    /// Bit `i` of the word is the bit at index `byte_index * 8 + i`.
+    /// Bytes past the end of the array are read as zero.
+    /// 
+    #[inline(always)]
+    const fn load_word(&self, byte_index: usize) -> u64 {
+        let mut word: u64 = 0;
+        let mut i: usize = 0;
+        while i < 8
+        && byte_index + i < N {
+            word |= (self.inner[byte_index + i] as u64) << (i << 3);
+            i += 1;
+        }
+        word
+    }
+
+    /// Reads `len` bits starting at `start` as a word, where bit `i` is the bit at index `start + i`.
+    ///
+    /// `len` must be at most 64 and `start + len` must be at most `L`.
+    /// 
+    #[inline(always)]
+    const fn load_bits(&self, start: usize, len: usize) -> u64 {
+        let byte_index: usize = start >> 3; // same as `start / 8`
+        let bit_offset: usize = start & 0b111; // same as `start % 8`
+        let mut word: u64 = self.load_word(byte_index) >> bit_offset;
+        if bit_offset > 0
+        && byte_index + 8 < N {
+            word |= (self.inner[byte_index + 8] as u64) << (64 - bit_offset);
+        }
+        if len < 64 {
+            word &= (1u64 << len) - 1;
+        }
+        word
+    }
+
+    /// Writes the low `len` bits of `word` starting at `start`, where bit `i` goes to index `start + i`.
+    ///
+    /// `len` must be at most 64 and `start + len` must be at most `L`. The other bits are left untouched.
+    /// 
+    #[inline(always)]
+    const fn store_bits(&mut self, start: usize, len: usize, word: u64) {
+        let mut written: usize = 0;
+        while written < len {
+            let index: usize = start + written;
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            let count: usize = if 8 - bit_offset < len - written { 8 - bit_offset } else { len - written };
+            let mask: u8 = (((1u16 << count) - 1) as u8) << bit_offset;
+            let bits: u8 = ((word >> written) as u8) << bit_offset;
+            self.inner[byte_index] = (self.inner[byte_index] & !mask) | (bits & mask);
+            written += count;
+        }
+    }
+
+    /// Wraps bytes in a [`Binvec`] without touching the unused bits.
+    ///
+    /// Every constructor goes through this, so a `Binvec<L, N>` where `N` is not `(L + 7) / 8` fails to compile.
+    /// 
+    #[inline(always)]
+    const fn from_inner(inner: [u8; N]) -> Self {
+        const { assert!(N == L.div_ceil(8), "N must be (L + 7) / 8, the number of bytes needed to store L bits") };
+        Self { inner }
+    }
+
+    /// Clears the unused bits beyond the length `L` in the last byte.
+    ///
+    /// Every method relies on those bits being `0`,
+    /// so this must be called after any byte-wise operation that may set them.
+    /// 
+    #[inline(always)]
+    const fn clear_unused_bits(&mut self) {
+        if L > 0
+        && !L.is_multiple_of(8) {
+            let last_bits: usize = L % 8;
+            let mask: u8 = (1u8 << last_bits) - 1;
+            self.inner[N - 1] &= mask;
+        }
+    }
+
+    /// Checks that `bytes` is a valid [`Binvec`], which means `N` is `(L + 7) / 8` and every unused bit is `0`.
+    ///
+    /// This is the check for casts that do not go through [`from_inner`](Binvec::from_inner).
+    /// 
+    #[inline(always)]
+    const fn is_valid_bytes(bytes: &[u8; N]) -> bool {
+        N == L.div_ceil(8)
+        && (L.is_multiple_of(8) || bytes[N - 1] >> (L % 8) == 0)
+    }
+}
+
+
+// impl Default
+impl<const L: usize, const N: usize> Default for Binvec<L, N> {
+    /// Creates a [`Binvec`] with every bit set to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<12, 2> = Default::default();
+    /// assert_eq!(binvec, binvec!(12, false));
+    /// ```
+    /// 
+    #[inline]
+    fn default() -> Self {
+        Self::from_inner([0x00; N])
+    }
+}
+
+
+// impl IntoIterator
+impl<const L: usize, const N: usize> IntoIterator for Binvec<L, N> {
+    type Item = bool;
+    type IntoIter = BinvecIntoIter<L, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BinvecIntoIter::new(self)
+    }
+}
+
+
+impl<'a, const L: usize, const N: usize> IntoIterator for &'a Binvec<L, N> {
+    type Item = bool;
+    type IntoIter = BinvecIter<'a, L, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+/// Creates a new [`Binvec`].
+/// 
+/// The macro has three forms:
+/// - `binvec!(len, initial_value)` creates a `len` length [`Binvec`] with every bit set to `initial_value`.
+/// - `binvec![bits: b0, b1, ...]` lists every bit, from index `0` upward, as `0`, `1`, `true`, or `false`.
+///   The length is the number of bits listed and the [`Binvec`] is built at compile time.
+///   The `bits:` prefix may be left out for one bit or for three or more bits;
+///   two arguments without it are always a length and an initial value.
+/// - `binvec!("0110...")` reads the bits from a string literal, where the first character is the bit at index `0`.
+///   The length is the number of characters, the [`Binvec`] is built at compile time,
+///   and any character other than `0` or `1` is a compile error.
 /// 
 /// ---
 /// # Arguments
 /// - `len`: Number of bits to store
 /// - `initial_value`: Initial value of the array
+/// - `bits: b0, b1, ...`: The bits to store, in index order
+/// - `"0110..."`: The bits to store as a string, in index order
 /// 
 /// ---
 /// # Returns
-/// An `len` length [`Binvec`] initialized with `initial_value`.
+/// An `len` length [`Binvec`] initialized with `initial_value`, or a [`Binvec`] holding the listed or written bits.
 /// 
 /// ---
 /// # Examples
@@ -417,30 +1554,57 @@ impl<'a, const L: usize, const N: usize> IntoIterator for &'a Binvec<L, N> {
 /// use binvec::*;
 /// 
 /// let binvec = binvec!(12, false);
+/// 
+/// let bits = binvec![1, 0, 1, 1, 0];
+/// assert_eq!(bits.len(), 5);
+/// assert_eq!(bits.to_u64(), Some(0b01101));
+/// 
+/// let flags = binvec![true, false, true];
+/// assert_eq!(flags.count_ones(), 2);
+/// 
+/// assert_eq!(binvec![bits: 1, 0].len(), 2);
+/// assert_eq!(binvec![bits: 1, 0, 1], binvec![1, 0, 1]);
+/// assert_eq!(binvec!(1, true).len(), 1);
+/// 
+/// let sync_word = binvec!("10110010");
+/// assert_eq!(sync_word, binvec![1, 0, 1, 1, 0, 0, 1, 0]);
 /// ```
 /// 
 #[macro_export]
 macro_rules! binvec {
+    (@bit 0) => { false };
+    (@bit 1) => { true };
+    (@bit true) => { true };
+    (@bit false) => { false };
+    (@bit $other:tt) => {
+        compile_error!(concat!("expected `0`, `1`, `true`, or `false` in `binvec!`, found `", stringify!($other), "`"))
+    };
+    (@bits $($bit:tt),*) => {{
+        const BITS: &[bool] = &[$($crate::binvec!(@bit $bit)),*];
+        const L: usize = BITS.len();
+        const N: usize = (L + 7) >> 3; // same as (L + 7) / 8
+        const BINVEC: Binvec<L, N> = Binvec::from_bool_array([$($crate::binvec!(@bit $bit)),*]);
+        BINVEC
+    }};
+    (@one 0) => { $crate::binvec!(@bits 0) };
+    (@one 1) => { $crate::binvec!(@bits 1) };
+    (@one true) => { $crate::binvec!(@bits true) };
+    (@one false) => { $crate::binvec!(@bits false) };
+    (@one $bits:literal) => {{
+        const BITS: &str = $bits;
+        const L: usize = BITS.len();
+        const N: usize = (L + 7) >> 3; // same as (L + 7) / 8
+        const BINVEC: Binvec<L, N> = Binvec::__from_bit_str(BITS);
+        BINVEC
+    }};
+    (@one $other:tt) => { $crate::binvec!(@bits $other) };
+    (bits: $($bit:tt),+ $(,)?) => { $crate::binvec!(@bits $($bit),+) };
     ($len:expr, $initial_value:expr) => {{
         const L: usize = $len;
         const N: usize = (L + 7) >> 3; // same as (L + 7) / 8
         #[allow(deprecated)]
         Binvec::<L, N>::new($initial_value)
     }};
-}
-
-
-// impl Display
-impl<const L: usize, const N: usize> core::fmt::Display for Binvec<L, N> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "[")?;
-        let mut iter: BinvecIter<'_, L, N> = self.iter();
-        if let Some(first) = iter.next() {
-            write!(f, "{}", if first { "1" } else { "0" })?;
-            for bit in iter {
-                write!(f, ", {}", if bit { "1" } else { "0" })?;
-            }
-        }
-        write!(f, "]")
-    }
+    ($bit0:tt $(,)?) => { $crate::binvec!(@one $bit0) };
+    ($bit0:tt, $bit1:tt, $($bit:tt),+ $(,)?) => { $crate::binvec!(@bits $bit0, $bit1, $($bit),+) };
 }
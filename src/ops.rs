@@ -0,0 +1,255 @@
+use core::cmp::Ordering;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign, Shr, ShrAssign};
+use crate::{Binvec, kernel};
+
+
+// impl BitAnd
+impl<const L: usize, const N: usize> BitAnd for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= &rhs;
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitAnd<&Binvec<L, N>> for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn bitand(self, rhs: &Binvec<L, N>) -> Self::Output {
+        let mut output: Binvec<L, N> = *self;
+        output &= rhs;
+        output
+    }
+}
+
+
+// impl BitAndAssign
+impl<const L: usize, const N: usize> BitAndAssign for Binvec<L, N> {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self &= &rhs;
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitAndAssign<&Binvec<L, N>> for Binvec<L, N> {
+    fn bitand_assign(&mut self, rhs: &Binvec<L, N>) {
+        kernel::bitand_assign(&mut self.inner, &rhs.inner); // unused bits stay 0 because both sides are 0
+    }
+}
+
+
+// impl BitOr
+impl<const L: usize, const N: usize> BitOr for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= &rhs;
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitOr<&Binvec<L, N>> for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn bitor(self, rhs: &Binvec<L, N>) -> Self::Output {
+        let mut output: Binvec<L, N> = *self;
+        output |= rhs;
+        output
+    }
+}
+
+
+// impl BitOrAssign
+impl<const L: usize, const N: usize> BitOrAssign for Binvec<L, N> {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self |= &rhs;
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitOrAssign<&Binvec<L, N>> for Binvec<L, N> {
+    fn bitor_assign(&mut self, rhs: &Binvec<L, N>) {
+        kernel::bitor_assign(&mut self.inner, &rhs.inner); // unused bits stay 0 because both sides are 0
+    }
+}
+
+
+// impl BitXor
+impl<const L: usize, const N: usize> BitXor for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= &rhs;
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitXor<&Binvec<L, N>> for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn bitxor(self, rhs: &Binvec<L, N>) -> Self::Output {
+        let mut output: Binvec<L, N> = *self;
+        output ^= rhs;
+        output
+    }
+}
+
+
+// impl BitXorAssign
+impl<const L: usize, const N: usize> BitXorAssign for Binvec<L, N> {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self ^= &rhs;
+    }
+}
+
+
+impl<const L: usize, const N: usize> BitXorAssign<&Binvec<L, N>> for Binvec<L, N> {
+    fn bitxor_assign(&mut self, rhs: &Binvec<L, N>) {
+        kernel::bitxor_assign(&mut self.inner, &rhs.inner); // unused bits stay 0 because both sides are 0
+    }
+}
+
+
+// impl Not
+impl<const L: usize, const N: usize> Not for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        self.flip_all();
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> Not for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+
+// impl Shl
+impl<const L: usize, const N: usize> Shl<usize> for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn shl(mut self, rhs: usize) -> Self::Output {
+        self.shift_left(rhs);
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> Shl<usize> for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn shl(self, rhs: usize) -> Self::Output {
+        *self << rhs
+    }
+}
+
+
+// impl ShlAssign
+impl<const L: usize, const N: usize> ShlAssign<usize> for Binvec<L, N> {
+    #[inline]
+    fn shl_assign(&mut self, rhs: usize) {
+        self.shift_left(rhs);
+    }
+}
+
+
+// impl Shr
+impl<const L: usize, const N: usize> Shr<usize> for Binvec<L, N> {
+    type Output = Self;
+
+    #[inline]
+    fn shr(mut self, rhs: usize) -> Self::Output {
+        self.shift_right(rhs);
+        self
+    }
+}
+
+
+impl<const L: usize, const N: usize> Shr<usize> for &Binvec<L, N> {
+    type Output = Binvec<L, N>;
+
+    #[inline]
+    fn shr(self, rhs: usize) -> Self::Output {
+        *self >> rhs
+    }
+}
+
+
+// impl ShrAssign
+impl<const L: usize, const N: usize> ShrAssign<usize> for Binvec<L, N> {
+    #[inline]
+    fn shr_assign(&mut self, rhs: usize) {
+        self.shift_right(rhs);
+    }
+}
+
+
+// impl Index
+impl<const L: usize, const N: usize> Index<usize> for Binvec<L, N> {
+    type Output = bool;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!("index out of bounds: the len is {L} but the index is {index}"),
+        }
+    }
+}
+
+
+// impl PartialOrd
+impl<const L: usize, const N: usize> PartialOrd for Binvec<L, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+// impl Ord
+impl<const L: usize, const N: usize> Ord for Binvec<L, N> {
+    /// Compares two binvecs numerically, treating the bit at index `L - 1` as the most significant bit.
+    ///
+    /// This is the same order as the integers returned by [`Binvec::to_u128`] and printed by `{:b}`,
+    /// not the lexicographic order of [`Binvec::iter`].
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let low = Binvec::<12, 2>::from_raw_bytes([0b1111_1111, 0b0000]);
+    /// let high = Binvec::<12, 2>::from_raw_bytes([0b0000_0000, 0b0001]);
+    /// assert!(low < high);
+    /// ```
+    /// 
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.iter().rev().cmp(other.inner.iter().rev()) // unused bits are always 0
+    }
+}
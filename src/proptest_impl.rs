@@ -0,0 +1,19 @@
+use proptest::arbitrary::{Arbitrary, StrategyFor, any};
+use proptest::array::{UniformArrayStrategy, uniform};
+use proptest::strategy::{Map, Strategy};
+use crate::Binvec;
+
+
+// impl Arbitrary
+impl<const L: usize, const N: usize> Arbitrary for Binvec<L, N> {
+    type Parameters = ();
+    type Strategy = Map<UniformArrayStrategy<StrategyFor<u8>, [u8; N]>, fn([u8; N]) -> Self>;
+
+    /// Generates `N` arbitrary bytes and clears the unused bits beyond the length `L`.
+    ///
+    /// Shrinking works on the packed bytes, so failing cases shrink towards the all-zero `Binvec`.
+    /// 
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        uniform(any::<u8>()).prop_map(Self::from_raw_bytes)
+    }
+}
@@ -0,0 +1,43 @@
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+use crate::Binvec;
+
+
+// impl rand
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Overwrites every bit of the [`Binvec`] with random values.
+    ///
+    /// Whole random bytes are generated, and the unused bits beyond the length `L` are cleared to zero.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `rng`: The random number generator to draw the bytes from.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.fill_random(&mut rng);
+    /// assert!(binvec.count_ones() <= 12);
+    /// ```
+    /// 
+    pub fn fill_random<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        rng.fill_bytes(&mut self.inner);
+        self.clear_unused_bits();
+    }
+}
+
+
+// impl Distribution
+impl<const L: usize, const N: usize> Distribution<Binvec<L, N>> for StandardUniform {
+    /// Samples a [`Binvec`] where every bit is independently `0` or `1` with equal probability.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Binvec<L, N> {
+        let mut binvec: Binvec<L, N> = Binvec::from_inner([0x00; N]);
+        binvec.fill_random(rng);
+        binvec
+    }
+}
@@ -0,0 +1,398 @@
+use core::ops::Range;
+use crate::{Binvec, error};
+
+
+// impl ranges
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Fills every bit in the given range with the specified bit value.
+    ///
+    /// The partial first and last bytes are masked, and the bytes in between are written whole.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to fill.
+    /// - `value`: The bit value to fill the range with (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was filled.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(20, false);
+    /// binvec.fill_range(3..17, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 14);
+    /// assert_eq!(binvec.first_one(), Some(3));
+    /// assert_eq!(binvec.last_one(), Some(16));
+    /// assert_eq!(binvec.fill_range(3..21, true), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    pub const fn fill_range(&mut self, range: Range<usize>, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        let start: usize = range.start;
+        let end: usize = range.end;
+        if start > end
+        || end > L {
+            return Err(error::IndexOutOfBounds);
+        }
+        if start == end {
+            return Ok(());
+        }
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            self.fill_masked(start_byte, start_mask & end_mask, value);
+            return Ok(());
+        }
+        self.fill_masked(start_byte, start_mask, value);
+        let byte: u8 = if value { 0xFF } else { 0x00 };
+        let mut i: usize = start_byte + 1;
+        while i < end_byte {
+            self.inner[i] = byte;
+            i += 1;
+        }
+        if end_mask != 0x00 {
+            self.fill_masked(end_byte, end_mask, value);
+        }
+        Ok(())
+    }
+
+    /// Sets every bit in the given range to `1`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to set.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was set.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set_range(4..8).unwrap();
+    /// assert_eq!(binvec.count_ones(), 4);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn set_range(&mut self, range: Range<usize>) -> Result<(), error::IndexOutOfBounds> {
+        self.fill_range(range, true)
+    }
+
+    /// Clears every bit in the given range to `0`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to clear.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was cleared.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// binvec.clear_range(4..8).unwrap();
+    /// assert_eq!(binvec.count_ones(), 8);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn clear_range(&mut self, range: Range<usize>) -> Result<(), error::IndexOutOfBounds> {
+        self.fill_range(range, false)
+    }
+
+    /// Inverts every bit in the given range.
+    ///
+    /// The bits are flipped by XOR with masks for the partial first and last bytes,
+    /// and the bytes in between are inverted whole.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to invert.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the range was inverted.
+    /// - `Err(IndexOutOfBounds)` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(20, false);
+    /// binvec.toggle_range(2..12).unwrap();
+    /// binvec.toggle_range(6..18).unwrap();
+    /// assert_eq!(binvec.count_ones_in_range(2..6), Some(4));
+    /// assert_eq!(binvec.count_ones_in_range(6..12), Some(0));
+    /// assert_eq!(binvec.count_ones_in_range(12..18), Some(6));
+    /// ```
+    /// 
+    pub const fn toggle_range(&mut self, range: Range<usize>) -> Result<(), error::IndexOutOfBounds> {
+        let start: usize = range.start;
+        let end: usize = range.end;
+        if start > end
+        || end > L {
+            return Err(error::IndexOutOfBounds);
+        }
+        if start == end {
+            return Ok(());
+        }
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            self.inner[start_byte] ^= start_mask & end_mask;
+            return Ok(());
+        }
+        self.inner[start_byte] ^= start_mask;
+        let mut i: usize = start_byte + 1;
+        while i < end_byte {
+            self.inner[i] = !self.inner[i];
+            i += 1;
+        }
+        if end_mask != 0x00 {
+            self.inner[end_byte] ^= end_mask;
+        }
+        Ok(())
+    }
+
+    /// Copies a compile-time range of bits into a new, smaller [`Binvec`].
+    ///
+    /// The bits are read up to 64 at a time, so fields of a packed bitmap can be split off without per-bit loops.
+    /// The range is checked at compile time.
+    ///
+    /// ---
+    /// # Generics
+    /// - `START`: The index of the first bit to copy.
+    /// - `LEN`: The number of bits to copy. `START + LEN` must not exceed `L`.
+    /// - `M`: The byte length of the result, which must be `(LEN + 7) / 8`. It can be written as `_` when the result type is annotated.
+    ///
+    /// ---
+    /// # Returns
+    /// A `LEN` length [`Binvec`] whose bit `i` is the bit at index `START + i`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let header: Binvec<16, 2> = "1010111100001111".parse().unwrap();
+    /// let flags = header.get_range::<0, 4, 1>();
+    /// let kind: Binvec<8, 1> = header.get_range::<4, 8, _>();
+    /// assert_eq!(format!("{flags:#}"), "1010");
+    /// assert_eq!(format!("{kind:#}"), "11110000");
+    /// ```
+    /// 
+    pub const fn get_range<const START: usize, const LEN: usize, const M: usize>(&self) -> Binvec<LEN, M> {
+        const {
+            assert!(START <= L && LEN <= L - START, "the range must be within the `Binvec`");
+            assert!(M == (LEN + 7) >> 3, "`M` must be `(LEN + 7) / 8`");
+        };
+        let mut range: Binvec<LEN, M> = Binvec::from_inner([0x00; M]);
+        let mut i: usize = 0;
+        while i < M {
+            let offset: usize = i << 3; // same as `i * 8`
+            let len: usize = if LEN - offset < 64 { LEN - offset } else { 64 };
+            let word: u64 = self.load_bits(START + offset, len);
+            let mut j: usize = 0;
+            while j < 8
+            && i + j < M {
+                range.inner[i + j] = (word >> (j << 3)) as u8;
+                j += 1;
+            }
+            i += 8;
+        }
+        range
+    }
+
+    /// Copies a range of bits from another [`Binvec`] into this one at an arbitrary offset.
+    ///
+    /// Neither the source range nor the destination needs to be byte-aligned;
+    /// the bits are moved up to 64 at a time with shifts and masks.
+    /// Bits outside the destination range are left untouched.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `src`: The [`Binvec`] to copy from. It may have a different length.
+    /// - `src_range`: The half-open range of indices `start..end` in `src` to copy.
+    /// - `dst_offset`: The index in `self` where the first copied bit is written.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bits were copied.
+    /// - `Err(IndexOutOfBounds)` if `src_range` is not within `src` or the copied bits do not fit in `self`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let field: Binvec<5, 1> = "10111".parse().unwrap();
+    /// let mut frame = binvec!(16, false);
+    /// frame.copy_bits_from(&field, 0..5, 6).unwrap();
+    /// assert_eq!(format!("{frame:#}"), "0000001011100000");
+    /// assert_eq!(frame.copy_bits_from(&field, 0..5, 12), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    pub const fn copy_bits_from<const L2: usize, const N2: usize>(
+        &mut self,
+        src: &Binvec<L2, N2>,
+        src_range: Range<usize>,
+        dst_offset: usize,
+    ) -> Result<(), error::IndexOutOfBounds> {
+        let start: usize = src_range.start;
+        let end: usize = src_range.end;
+        if start > end
+        || end > L2
+        || dst_offset > L
+        || end - start > L - dst_offset {
+            return Err(error::IndexOutOfBounds);
+        }
+        let mut copied: usize = 0;
+        while copied < end - start {
+            let len: usize = if end - start - copied < 64 { end - start - copied } else { 64 };
+            let word: u64 = src.load_bits(start + copied, len);
+            self.store_bits(dst_offset + copied, len, word);
+            copied += len;
+        }
+        Ok(())
+    }
+
+    /// Copies a range of bits to another position within the same [`Binvec`].
+    ///
+    /// The source and destination may overlap; the result is as if the source bits were copied
+    /// to a temporary first, like [`slice::copy_within`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `src_range`: The half-open range of indices `start..end` to copy.
+    /// - `dst_offset`: The index where the first copied bit is written.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bits were copied.
+    /// - `Err(IndexOutOfBounds)` if `src_range` or the destination is not within the [`Binvec`].
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec: Binvec<8, 1> = "11010000".parse().unwrap();
+    /// binvec.copy_within(0..4, 2).unwrap();
+    /// assert_eq!(format!("{binvec:#}"), "11110100");
+    /// ```
+    /// 
+    #[inline]
+    pub const fn copy_within(&mut self, src_range: Range<usize>, dst_offset: usize) -> Result<(), error::IndexOutOfBounds> {
+        let src: Binvec<L, N> = *self;
+        self.copy_bits_from(&src, src_range, dst_offset)
+    }
+
+    /// Splits the [`Binvec`] at a compile-time index into a prefix and a suffix.
+    ///
+    /// The lengths are checked at compile time, and are usually inferred from the annotated result type.
+    ///
+    /// ---
+    /// # Generics
+    /// - `MID`: The split index, which is the length of the prefix.
+    /// - `N1`: The byte length of the prefix, which must be `(MID + 7) / 8`.
+    /// - `L2`: The length of the suffix, which must be `L - MID`.
+    /// - `N2`: The byte length of the suffix, which must be `(L2 + 7) / 8`.
+    ///
+    /// ---
+    /// # Returns
+    /// A tuple of the bits `[0, MID)` and the bits `[MID, L)`, each starting at index `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let frame: Binvec<12, 2> = "101100001111".parse().unwrap();
+    /// let (header, payload): (Binvec<4, 1>, Binvec<8, 1>) = frame.split_at();
+    /// assert_eq!(format!("{header:#}"), "1011");
+    /// assert_eq!(format!("{payload:#}"), "00001111");
+    /// ```
+    /// 
+    #[inline]
+    pub const fn split_at<const MID: usize, const N1: usize, const L2: usize, const N2: usize>(&self) -> (Binvec<MID, N1>, Binvec<L2, N2>) {
+        const { assert!(MID <= L && L2 == L - MID, "the lengths of the halves must add up to `L`") };
+        (self.get_range::<0, MID, N1>(), self.get_range::<MID, L2, N2>())
+    }
+
+    /// Concatenates two [`Binvec`]s into a new, longer one.
+    ///
+    /// The bits of `other` are appended after the bits of `self`, stitched with shifts and masks
+    /// when `L` is not a multiple of 8. The lengths are checked at compile time.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] whose bits are placed after the bits of `self`.
+    ///
+    /// ---
+    /// # Generics
+    /// - `L3`: The length of the result, which must be `L + L2`.
+    /// - `N3`: The byte length of the result, which must be `(L3 + 7) / 8`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the bits of `self` at `[0, L)` and the bits of `other` at `[L, L3)`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let header: Binvec<3, 1> = "101".parse().unwrap();
+    /// let payload: Binvec<9, 2> = "001110001".parse().unwrap();
+    /// let packet: Binvec<12, 2> = header.concat(&payload);
+    /// assert_eq!(format!("{packet:#}"), "101001110001");
+    /// ```
+    /// 
+    pub const fn concat<const L2: usize, const N2: usize, const L3: usize, const N3: usize>(&self, other: &Binvec<L2, N2>) -> Binvec<L3, N3> {
+        const {
+            assert!(L3 == L + L2, "`L3` must be the sum of both lengths");
+            assert!(N3 == (L3 + 7) >> 3, "`N3` must be `(L3 + 7) / 8`");
+        };
+        let mut result: Binvec<L3, N3> = Binvec::from_inner([0x00; N3]);
+        let mut i: usize = 0;
+        while i < N {
+            result.inner[i] = self.inner[i]; // unused bits of `self` are 0 and get overwritten below
+            i += 1;
+        }
+        let mut copied: usize = 0;
+        while copied < L2 {
+            let len: usize = if L2 - copied < 64 { L2 - copied } else { 64 };
+            result.store_bits(L + copied, len, other.load_bits(copied, len));
+            copied += len;
+        }
+        result
+    }
+
+    /// Sets or clears the bits selected by `mask` in the byte at `byte_index`.
+    /// 
+    #[inline(always)]
+    const fn fill_masked(&mut self, byte_index: usize, mask: u8, value: bool) {
+        if value {
+            self.inner[byte_index] |= mask;
+        } else {
+            self.inner[byte_index] &= !mask;
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{Binvec, kernel};
+
+
+/// A precomputed index over a `Binvec` that answers `rank` and `select` queries quickly.
+///
+/// The bits are split into blocks of 512 bits. The index stores the number of `1` bits before every block,
+/// so [`rank`](RankSelectIndex::rank) adds at most eight [`u64`] popcounts to one stored count and runs in `O(1)`.
+/// It also samples the block that holds every 512th `1` bit, so [`select`](RankSelectIndex::select)
+/// starts at the sampled block and only searches the blocks up to the next sample.
+/// That is a constant number of blocks unless the `1` bits are very sparse, and `O(log B)` in the worst case.
+/// The index borrows the `Binvec`, so the bits cannot change while the index is alive.
+///
+/// ---
+/// # Generics
+/// - `L`: The number of bits of the indexed `Binvec`, at most [`u32::MAX`].
+/// - `N`: The number of bytes of the indexed `Binvec`.
+/// - `B`: The number of blocks, which must be `(L + 511) / 512`. It has to be written out, while `L` and `N` can be written as `_`.
+///
+/// ---
+/// # Note
+/// The index takes 8 bytes per 64 bytes of the `Binvec`, stored inline.
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankSelectIndex<'a, const L: usize, const N: usize, const B: usize> {
+    binvec: &'a Binvec<L, N>,
+    blocks: [u32; B],
+    samples: [u32; B],
+    ones: usize,
+}
+
+
+impl<'a, const L: usize, const N: usize, const B: usize> RankSelectIndex<'a, L, N, B> {
+    /// Creates a new `RankSelectIndex` for the given `Binvec`.
+    ///
+    /// This scans the `Binvec` once.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `binvec`: A reference to the `Binvec` to index.
+    ///
+    /// ---
+    /// # Returns
+    /// A new `RankSelectIndex` holding the cumulative popcount of every block and the select samples.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let binvec = binvec!(1200, true);
+    /// let index = RankSelectIndex::<_, _, 3>::new(&binvec);
+    /// assert_eq!(index.count_ones(), 1200);
+    /// ```
+    /// 
+    pub const fn new(binvec: &'a Binvec<L, N>) -> Self {
+        const {
+            assert!(L <= u32::MAX as usize, "`RankSelectIndex` supports at most `u32::MAX` bits");
+            assert!(B == (L + 511) >> 9, "`B` must be `(L + 511) / 512`");
+        };
+        let mut blocks: [u32; B] = [0; B];
+        let mut samples: [u32; B] = [0; B];
+        let mut ones: usize = 0;
+        let mut sample: usize = 0;
+        let mut block: usize = 0;
+        while block < B {
+            blocks[block] = ones as u32;
+            let mut word: usize = 0;
+            while word < 8 {
+                let byte_index: usize = (block << 6) + (word << 3); // same as `block * 64 + word * 8`
+                if byte_index < N {
+                    ones += binvec.load_word(byte_index).count_ones() as usize;
+                }
+                word += 1;
+            }
+            // record this block for every 512th `1` bit that falls in it
+            while sample << 9 < ones {
+                samples[sample] = block as u32;
+                sample += 1;
+            }
+            block += 1;
+        }
+        Self { binvec, blocks, samples, ones }
+    }
+
+    /// Returns the total number of bits set to `1` in the indexed `Binvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// The count of `1` bits, computed once when the index was built.
+    /// 
+    #[inline(always)]
+    pub const fn count_ones(&self) -> usize {
+        self.ones
+    }
+
+    /// Counts the bits set to `1` strictly before the given index in constant time.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `index`: The exclusive end of the counted range `[0, index)`. `L` itself is allowed.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(count)` of the bits that are `1` in `[0, index)`.
+    /// - `None` if `index` is greater than `L`.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let mut binvec = binvec!(1200, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(1000, true).unwrap();
+    /// let index: RankSelectIndex<1200, 150, 3> = RankSelectIndex::new(&binvec);
+    /// assert_eq!(index.rank(3), Some(1));
+    /// assert_eq!(index.rank(1000), Some(1));
+    /// assert_eq!(index.rank(1200), Some(2));
+    /// assert_eq!(index.rank(1201), None);
+    /// ```
+    /// 
+    pub const fn rank(&self, index: usize) -> Option<usize> {
+        if index > L {
+            return None;
+        }
+        if index == L {
+            return Some(self.ones);
+        }
+        let block: usize = index >> 9; // same as `index / 512`
+        let mut count: usize = self.blocks[block] as usize;
+        let mut word: usize = block << 3; // the first word of the block
+        while word < index >> 6 {
+            count += self.binvec.load_word(word << 3).count_ones() as usize;
+            word += 1;
+        }
+        let bit_offset: usize = index & 0b11_1111; // same as `index % 64`
+        let mask: u64 = (1u64 << bit_offset) - 1;
+        count += (self.binvec.load_word(word << 3) & mask).count_ones() as usize;
+        Some(count)
+    }
+
+    /// Returns the index of the `n`-th bit set to `1`, counting from `0`.
+    ///
+    /// The search starts at the block sampled for the `n / 512`-th sample
+    /// and binary searches the blocks up to the next sample, then scans at most eight words.
+    ///
+    /// ---
+    /// # Parameters
+    /// - `n`: The zero-based rank of the `1` bit to find.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the `n`-th bit that is `1`.
+    /// - `None` if there are `n` or fewer bits set to `1`.
+    ///
+    /// ---
+    /// # Example
+    /// ```
+    /// use binvec::*;
+    /// let mut binvec = binvec!(1200, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(1000, true).unwrap();
+    /// let index = RankSelectIndex::<_, _, 3>::new(&binvec);
+    /// assert_eq!(index.select(1), Some(1000));
+    /// assert_eq!(index.select(2), None);
+    ///
+    /// let all = binvec!(1200, true);
+    /// let index = RankSelectIndex::<_, _, 3>::new(&all);
+    /// assert_eq!(index.select(1100), Some(1100));
+    /// ```
+    /// 
+    pub const fn select(&self, n: usize) -> Option<usize> {
+        if n >= self.ones {
+            return None;
+        }
+        let sample: usize = n >> 9; // same as `n / 512`
+        let mut low: usize = self.samples[sample] as usize;
+        let mut high: usize = if (sample + 1) << 9 < self.ones {
+            self.samples[sample + 1] as usize + 1
+        } else {
+            B
+        };
+        // find the last block whose cumulative count is not greater than `n`
+        while high - low > 1 {
+            let mid: usize = low + (high - low) / 2;
+            if self.blocks[mid] as usize <= n {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let mut remaining: usize = n - self.blocks[low] as usize;
+        let mut word: usize = low << 3; // the first word of the block
+        loop {
+            let bits: u64 = self.binvec.load_word(word << 3);
+            let ones: usize = bits.count_ones() as usize;
+            if remaining < ones {
+                return match kernel::select(&bits.to_le_bytes(), remaining) {
+                    Some(offset) => Some((word << 6) + offset),
+                    None => None,
+                };
+            }
+            remaining -= ones;
+            word += 1;
+        }
+    }
+}
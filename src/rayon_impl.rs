@@ -0,0 +1,148 @@
+use rayon::prelude::*;
+use crate::{Binvec, kernel};
+
+
+/// The number of bytes each parallel task works on, chosen so that tiny tasks do not dominate the cost.
+const CHUNK_BYTES: usize = 4096;
+
+
+// impl rayon
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Returns a parallel iterator over the bits of the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// An indexed parallel iterator that yields each bit as a `bool`, in order when collected.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let binvec = binvec!(10_000, true);
+    /// assert!(binvec.par_iter().all(|bit| bit));
+    /// ```
+    /// 
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = bool> + '_ {
+        // SAFETY: index is guaranteed to be less than L
+        (0..L).into_par_iter().map(move |index| unsafe { self.get_unchecked(index) })
+    }
+
+    /// Returns a parallel iterator over the indices of the bits set to `1`.
+    ///
+    /// The bytes are split into chunks that are scanned on different threads.
+    ///
+    /// ---
+    /// # Returns
+    /// A parallel iterator that yields each index, in ascending order when collected.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut binvec = binvec!(10_000, false);
+    /// binvec.set(3, true).unwrap();
+    /// binvec.set(9_999, true).unwrap();
+    /// assert_eq!(binvec.par_iter_ones().collect::<Vec<_>>(), [3, 9_999]);
+    /// ```
+    /// 
+    pub fn par_iter_ones(&self) -> impl ParallelIterator<Item = usize> + '_ {
+        self.inner.par_chunks(CHUNK_BYTES).enumerate().flat_map_iter(|(chunk_index, chunk)| {
+            let base: usize = chunk_index * CHUNK_BYTES;
+            chunk.iter().enumerate().flat_map(move |(i, &byte)| {
+                (0..8).filter(move |bit| (byte >> bit) & 1 != 0).map(move |bit| ((base + i) << 3) + bit) // unused bits are always 0
+            })
+        })
+    }
+
+    /// Counts the number of bits set to `1` in parallel.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`, the same as [`Binvec::count_ones`].
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(100_000, true);
+    /// assert_eq!(binvec.par_count_ones(), 100_000);
+    /// ```
+    /// 
+    pub fn par_count_ones(&self) -> usize {
+        self.inner
+            .par_chunks(CHUNK_BYTES)
+            .map(kernel::count_ones_simd)
+            .sum()
+    }
+
+    /// Performs `self &= other` in parallel.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = binvec!(100_000, true);
+    /// a.par_bitand_assign(&binvec!(100_000, false));
+    /// assert!(a.is_all_zero());
+    /// ```
+    /// 
+    pub fn par_bitand_assign(&mut self, other: &Self) {
+        self.inner.par_chunks_mut(CHUNK_BYTES).zip(other.inner.par_chunks(CHUNK_BYTES)).for_each(|(lhs, rhs)| {
+            kernel::bitand_assign(lhs, rhs);
+        });
+    }
+
+    /// Performs `self |= other` in parallel.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = binvec!(100_000, false);
+    /// a.par_bitor_assign(&binvec!(100_000, true));
+    /// assert!(a.is_all_one());
+    /// ```
+    /// 
+    pub fn par_bitor_assign(&mut self, other: &Self) {
+        self.inner.par_chunks_mut(CHUNK_BYTES).zip(other.inner.par_chunks(CHUNK_BYTES)).for_each(|(lhs, rhs)| {
+            kernel::bitor_assign(lhs, rhs);
+        });
+    }
+
+    /// Performs `self ^= other` in parallel.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = binvec!(100_000, true);
+    /// a.par_bitxor_assign(&binvec!(100_000, true));
+    /// assert!(a.is_all_zero());
+    /// ```
+    /// 
+    pub fn par_bitxor_assign(&mut self, other: &Self) {
+        self.inner.par_chunks_mut(CHUNK_BYTES).zip(other.inner.par_chunks(CHUNK_BYTES)).for_each(|(lhs, rhs)| {
+            kernel::bitxor_assign(lhs, rhs);
+        });
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{Binvec, kernel};
+
+
+// impl search
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Returns the index of the first bit set to `1`.
+    ///
+    /// Eight bytes are scanned at once, so sparse vectors are searched quickly.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the lowest bit that is `1`.
+    /// - `None` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.first_one(), None);
+    /// binvec.set(9, true).unwrap();
+    /// binvec.set(4, true).unwrap();
+    /// assert_eq!(binvec.first_one(), Some(4));
+    /// ```
+    /// 
+    pub const fn first_one(&self) -> Option<usize> {
+        kernel::first_one(&self.inner, false) // unused bits are always 0
+    }
+
+    /// Returns the index of the first bit set to `0`.
+    ///
+    /// Eight bytes are scanned at once, so nearly full vectors are searched quickly.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the lowest bit that is `0`.
+    /// - `None` if every bit is `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// assert_eq!(binvec.first_zero(), None);
+    /// binvec.set(9, false).unwrap();
+    /// binvec.set(4, false).unwrap();
+    /// assert_eq!(binvec.first_zero(), Some(4));
+    /// ```
+    /// 
+    pub const fn first_zero(&self) -> Option<usize> {
+        match kernel::first_one(&self.inner, true) {
+            Some(index) if index < L => Some(index),
+            _ => None, // the unused bits are 1 after inverting
+        }
+    }
+
+    /// Returns the index of the last bit set to `1`.
+    ///
+    /// Eight bytes are scanned at once starting from the end.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the highest bit that is `1`.
+    /// - `None` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.last_one(), None);
+    /// binvec.set(4, true).unwrap();
+    /// binvec.set(9, true).unwrap();
+    /// assert_eq!(binvec.last_one(), Some(9));
+    /// ```
+    /// 
+    pub const fn last_one(&self) -> Option<usize> {
+        kernel::last_one(&self.inner) // unused bits are always 0
+    }
+
+    /// Returns the index of the last bit set to `0`.
+    ///
+    /// Whole bytes are scanned at once starting from the end.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the highest bit that is `0`.
+    /// - `None` if every bit is `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// assert_eq!(binvec.last_zero(), None);
+    /// binvec.set(4, false).unwrap();
+    /// binvec.set(9, false).unwrap();
+    /// assert_eq!(binvec.last_zero(), Some(9));
+    /// ```
+    /// 
+    pub const fn last_zero(&self) -> Option<usize> {
+        let mut i: usize = N;
+        while i > 0 {
+            i -= 1;
+            let mut byte: u8 = !self.inner[i];
+            if i == N - 1
+            && !L.is_multiple_of(8) {
+                byte &= (1u8 << (L % 8)) - 1; // the unused bits are 1 after inverting
+            }
+            if byte != 0x00 {
+                return Some((i << 3) + 7 - byte.leading_zeros() as usize);
+            }
+        }
+        None
+    }
+
+    /// Counts the consecutive bits set to `0` starting from the highest index.
+    ///
+    /// This matches [`u8::leading_zeros`] when the bit at index `L - 1` is seen as the most significant bit.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of leading `0` bits, or `L` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.leading_zeros(), 12);
+    /// binvec.set(8, true).unwrap();
+    /// assert_eq!(binvec.leading_zeros(), 3);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn leading_zeros(&self) -> usize {
+        match self.last_one() {
+            Some(index) => L - 1 - index,
+            None => L,
+        }
+    }
+
+    /// Counts the consecutive bits set to `1` starting from the highest index.
+    ///
+    /// This matches [`u8::leading_ones`] when the bit at index `L - 1` is seen as the most significant bit.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of leading `1` bits, or `L` if every bit is `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// assert_eq!(binvec.leading_ones(), 12);
+    /// binvec.set(8, false).unwrap();
+    /// assert_eq!(binvec.leading_ones(), 3);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn leading_ones(&self) -> usize {
+        match self.last_zero() {
+            Some(index) => L - 1 - index,
+            None => L,
+        }
+    }
+
+    /// Counts the consecutive bits set to `0` starting from index `0`.
+    ///
+    /// This matches [`u8::trailing_zeros`] when the bit at index `0` is seen as the least significant bit.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of trailing `0` bits, or `L` if every bit is `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.trailing_zeros(), 12);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.trailing_zeros(), 3);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn trailing_zeros(&self) -> usize {
+        match self.first_one() {
+            Some(index) => index,
+            None => L,
+        }
+    }
+
+    /// Counts the consecutive bits set to `1` starting from index `0`.
+    ///
+    /// This matches [`u8::trailing_ones`] when the bit at index `0` is seen as the least significant bit.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of trailing `1` bits, or `L` if every bit is `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// assert_eq!(binvec.trailing_ones(), 12);
+    /// binvec.set(3, false).unwrap();
+    /// assert_eq!(binvec.trailing_ones(), 3);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn trailing_ones(&self) -> usize {
+        match self.first_zero() {
+            Some(index) => index,
+            None => L,
+        }
+    }
+
+    /// Returns the index of the `n`-th bit set to `1`, counting from `0`.
+    ///
+    /// Whole bytes are skipped by their popcount, so only the byte containing the answer is inspected bit by bit.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `n`: The zero-based rank of the `1` bit to find.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the `n`-th bit that is `1`.
+    /// - `None` if there are `n` or fewer bits set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(7, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// assert_eq!(binvec.select(0), Some(2));
+    /// assert_eq!(binvec.select(2), Some(10));
+    /// assert_eq!(binvec.select(3), None);
+    /// ```
+    /// 
+    pub const fn select(&self, n: usize) -> Option<usize> {
+        kernel::select(&self.inner, n) // unused bits are always 0
+    }
+
+    /// Counts the bits set to `1` strictly before the given index.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The exclusive end of the counted range `[0, index)`. `L` itself is allowed.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(count)` of the bits that are `1` in `[0, index)`.
+    /// - `None` if `index` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(2, true).unwrap();
+    /// binvec.set(7, true).unwrap();
+    /// binvec.set(10, true).unwrap();
+    /// assert_eq!(binvec.rank(2), Some(0));
+    /// assert_eq!(binvec.rank(8), Some(2));
+    /// assert_eq!(binvec.rank(12), Some(3));
+    /// assert_eq!(binvec.rank(13), None);
+    /// ```
+    /// 
+    pub const fn rank(&self, index: usize) -> Option<usize> {
+        if index > L {
+            return None;
+        }
+        Some(kernel::rank(&self.inner, index))
+    }
+
+    /// Returns the start index of the first run of at least `len` consecutive bits set to `0`.
+    ///
+    /// Bytes that are all `0` extend the run by 8 and bytes that are all `1` reset it,
+    /// so only the bytes at the edges of a run are inspected bit by bit.
+    /// This is the first-fit search of a bitmap allocator.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `len`: The number of consecutive `0` bits required.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(index)` of the first bit of the lowest such run. A `len` of `0` always gives `Some(0)`.
+    /// - `None` if there is no run of `len` bits set to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(24, true);
+    /// binvec.clear_range(3..5).unwrap();
+    /// binvec.clear_range(9..20).unwrap();
+    /// assert_eq!(binvec.find_zero_run(2), Some(3));
+    /// assert_eq!(binvec.find_zero_run(3), Some(9));
+    /// assert_eq!(binvec.find_zero_run(12), None);
+    /// ```
+    /// 
+    pub const fn find_zero_run(&self, len: usize) -> Option<usize> {
+        if len == 0 {
+            return Some(0);
+        }
+        let mut run_start: usize = 0;
+        let mut run_len: usize = 0;
+        let mut i: usize = 0;
+        while i < N {
+            let byte: u8 = self.inner[i];
+            let base: usize = i << 3; // same as `i * 8`
+            if byte == 0x00
+            && base + 8 <= L {
+                if run_len == 0 {
+                    run_start = base;
+                }
+                run_len += 8;
+            } else if byte == 0xFF {
+                run_len = 0;
+            } else {
+                let mut bit: usize = 0;
+                while bit < 8
+                && base + bit < L {
+                    if (byte >> bit) & 1 != 0 {
+                        run_len = 0;
+                    } else {
+                        if run_len == 0 {
+                            run_start = base + bit;
+                        }
+                        run_len += 1;
+                        if run_len >= len {
+                            return Some(run_start);
+                        }
+                    }
+                    bit += 1;
+                }
+            }
+            if run_len >= len {
+                return Some(run_start);
+            }
+            i += 1;
+        }
+        None
+    }
+}
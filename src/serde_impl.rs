@@ -0,0 +1,138 @@
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use crate::Binvec;
+
+
+// impl Serialize
+impl<const L: usize, const N: usize> Serialize for Binvec<L, N> {
+    /// Serializes the bits as a string of `0` and `1` in human-readable formats,
+    /// or as the packed bytes in binary formats.
+    ///
+    /// The packed bytes are written as a fixed-size tuple of `N` bytes,
+    /// so formats such as `postcard` and `bincode` do not add a length prefix.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!("101100101101");
+    /// assert_eq!(serde_json::to_string(&binvec).unwrap(), "\"101100101101\"");
+    /// assert_eq!(postcard::to_allocvec(&binvec).unwrap(), [0b0100_1101, 0b1011]);
+    /// ```
+    /// 
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{self:#}"))
+        } else {
+            let mut tuple = serializer.serialize_tuple(N)?;
+            for byte in self.inner.iter() {
+                tuple.serialize_element(byte)?;
+            }
+            tuple.end()
+        }
+    }
+}
+
+
+// impl Deserialize
+impl<'de, const L: usize, const N: usize> Deserialize<'de> for Binvec<L, N> {
+    /// Deserializes a string of `0` and `1` or a sequence of [`bool`] in human-readable formats,
+    /// or the packed bytes in binary formats.
+    ///
+    /// The number of bits or bytes must match exactly, and the unused bits of the packed bytes must be `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!("101100101101");
+    /// let json: String = serde_json::to_string(&binvec).unwrap();
+    /// assert_eq!(serde_json::from_str::<Binvec<12, 2>>(&json).unwrap(), binvec);
+    /// let bytes: Vec<u8> = postcard::to_allocvec(&binvec).unwrap();
+    /// assert_eq!(postcard::from_bytes::<Binvec<12, 2>>(&bytes).unwrap(), binvec);
+    ///
+    /// // a sequence of booleans is accepted in human-readable formats
+    /// let bits = serde_json::from_str::<Binvec<3, 1>>("[true, false, true]").unwrap();
+    /// assert_eq!(bits, binvec![1, 0, 1]);
+    ///
+    /// // the length must match
+    /// assert!(serde_json::from_str::<Binvec<3, 1>>("\"10\"").is_err());
+    /// assert!(serde_json::from_str::<Binvec<3, 1>>("[true, false]").is_err());
+    /// assert!(serde_json::from_str::<Binvec<3, 1>>("[true, false, true, true]").is_err());
+    /// assert!(postcard::from_bytes::<Binvec<12, 2>>(&[0xFF]).is_err());
+    ///
+    /// // the unused bits must be `0`
+    /// assert!(postcard::from_bytes::<Binvec<12, 2>>(&[0xFF, 0x1F]).is_err());
+    /// ```
+    /// 
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BinvecVisitor { human_readable: true, marker: PhantomData })
+        } else {
+            deserializer.deserialize_tuple(N, BinvecVisitor { human_readable: false, marker: PhantomData })
+        }
+    }
+}
+
+
+/// A serde visitor that builds a `Binvec` and validates its length and padding bits.
+/// 
+struct BinvecVisitor<const L: usize, const N: usize> {
+    human_readable: bool,
+    marker: PhantomData<Binvec<L, N>>,
+}
+
+
+impl<'de, const L: usize, const N: usize> Visitor<'de> for BinvecVisitor<L, N> {
+    type Value = Binvec<L, N>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.human_readable {
+            write!(f, "a string of {L} `0` or `1` digits or a sequence of {L} booleans")
+        } else {
+            write!(f, "{N} packed bytes")
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let bytes: [u8; N] = v.try_into().map_err(|_| E::invalid_length(v.len(), &self))?;
+        Binvec::from_bytes(bytes).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        if self.human_readable {
+            let mut binvec: Binvec<L, N> = Binvec::from_inner([0x00; N]);
+            for index in 0..L {
+                match seq.next_element::<bool>()? {
+                    // SAFETY: index is guaranteed to be less than L
+                    Some(bit) => unsafe { binvec.set_unchecked(index, bit) },
+                    None => return Err(de::Error::invalid_length(index, &self)),
+                }
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(L + 1, &self));
+            }
+            Ok(binvec)
+        } else {
+            let mut bytes: [u8; N] = [0x00; N];
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                match seq.next_element::<u8>()? {
+                    Some(value) => *byte = value,
+                    None => return Err(de::Error::invalid_length(index, &self)),
+                }
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+            Binvec::from_bytes(bytes).map_err(de::Error::custom)
+        }
+    }
+}
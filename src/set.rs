@@ -0,0 +1,408 @@
+use crate::{Binvec, error};
+use crate::kernel::{self, Combine};
+
+
+// impl set
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Adds a value to the [`Binvec`] seen as a set of integers in `0..L`.
+    ///
+    /// The value `i` is in the set when the bit at index `i` is `1`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The integer to add.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(true)` if `value` was not in the set before.
+    /// - `Ok(false)` if it was already present.
+    /// - `Err(IndexOutOfBounds)` if `value` is not less than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut set = binvec!(64, false);
+    /// assert_eq!(set.insert(5), Ok(true));
+    /// assert_eq!(set.insert(5), Ok(false));
+    /// assert!(set.contains(5));
+    /// assert_eq!(set.insert(64), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn insert(&mut self, value: usize) -> Result<bool, error::IndexOutOfBounds> {
+        if value >= L {
+            return Err(error::IndexOutOfBounds);
+        }
+        let mask: u8 = 1 << (value & 0b111);
+        let byte: &mut u8 = &mut self.inner[value >> 3];
+        let inserted: bool = *byte & mask == 0;
+        *byte |= mask;
+        Ok(inserted)
+    }
+
+    /// Removes a value from the [`Binvec`] seen as a set of integers in `0..L`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The integer to remove.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(true)` if `value` was in the set.
+    /// - `Ok(false)` if it was not present.
+    /// - `Err(IndexOutOfBounds)` if `value` is not less than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut set = binvec!(64, false);
+    /// set.insert(5).unwrap();
+    /// assert_eq!(set.remove(5), Ok(true));
+    /// assert_eq!(set.remove(5), Ok(false));
+    /// assert_eq!(set.remove(64), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn remove(&mut self, value: usize) -> Result<bool, error::IndexOutOfBounds> {
+        if value >= L {
+            return Err(error::IndexOutOfBounds);
+        }
+        let mask: u8 = 1 << (value & 0b111);
+        let byte: &mut u8 = &mut self.inner[value >> 3];
+        let removed: bool = *byte & mask != 0;
+        *byte &= !mask;
+        Ok(removed)
+    }
+
+    /// Checks whether a value is in the [`Binvec`] seen as a set of integers in `0..L`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The integer to look up.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the bit at index `value` is `1`, and `false` if it is `0` or `value` is not less than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut set = binvec!(64, false);
+    /// set.insert(5).unwrap();
+    /// assert_eq!(set.contains(5), true);
+    /// assert_eq!(set.contains(6), false);
+    /// assert_eq!(set.contains(100), false);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn contains(&self, value: usize) -> bool {
+        value < L
+        && (self.inner[value >> 3] >> (value & 0b111)) & 1 != 0
+    }
+
+    /// Removes every value from the [`Binvec`] seen as a set, setting every bit to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut set = binvec!(64, false);
+    /// set.insert(5).unwrap();
+    /// set.insert(9).unwrap();
+    /// set.clear();
+    /// assert!(set.is_all_zero());
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn clear(&mut self) {
+        self.fill(false);
+    }
+
+    /// Checks whether every bit set to `1` in `self` is also `1` in `other`.
+    ///
+    /// The bytes are compared a word at a time and the check stops at the first bit that is only in `self`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare against.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if `self & !other` is all `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let granted = Binvec::<12, 2>::from_raw_bytes([0b0000_1111, 0x00]);
+    /// let required = Binvec::<12, 2>::from_raw_bytes([0b0000_0101, 0x00]);
+    /// assert!(required.is_subset(&granted));
+    /// assert!(!granted.is_subset(&required));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        !kernel::any_combined(&self.inner, &other.inner, Combine::AndNot)
+    }
+
+    /// Checks whether every bit set to `1` in `other` is also `1` in `self`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare against.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if `other & !self` is all `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let granted = Binvec::<12, 2>::from_raw_bytes([0b0000_1111, 0x00]);
+    /// let required = Binvec::<12, 2>::from_raw_bytes([0b0000_0101, 0x00]);
+    /// assert!(granted.is_superset(&required));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Checks whether `self` and `other` have no bit set to `1` at the same index.
+    ///
+    /// The check stops at the first word where both have a bit set to `1`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare against.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if `self & other` is all `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0b0000_1100, 0x00]);
+    /// assert!(a.is_disjoint(&b));
+    /// assert!(!a.is_disjoint(&a));
+    /// ```
+    /// 
+    #[inline]
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        !kernel::any_combined(&self.inner, &other.inner, Combine::And)
+    }
+
+    /// Returns the union of `self` and `other` as a new [`Binvec`].
+    ///
+    /// This is the same as `self | other` and only names the intent.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the bits set to `1` in `self`, `other`, or both.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0b0000_0110, 0x00]);
+    /// assert_eq!(a.union(&b).as_bytes(), &[0b0000_0111, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result: Binvec<L, N> = *self;
+        result.union_assign(other);
+        result
+    }
+
+    /// Replaces `self` with the union of `self` and `other`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// a.union_assign(&Binvec::from_raw_bytes([0b0000_0110, 0x00]));
+    /// assert_eq!(a.as_bytes(), &[0b0000_0111, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn union_assign(&mut self, other: &Self) {
+        kernel::bitor_assign(&mut self.inner, &other.inner); // unused bits stay 0 because both sides are 0
+    }
+
+    /// Returns the intersection of `self` and `other` as a new [`Binvec`].
+    ///
+    /// This is the same as `self & other` and only names the intent.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the bits set to `1` in both `self` and `other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0b0000_0110, 0x00]);
+    /// assert_eq!(a.intersection(&b).as_bytes(), &[0b0000_0010, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result: Binvec<L, N> = *self;
+        result.intersection_assign(other);
+        result
+    }
+
+    /// Replaces `self` with the intersection of `self` and `other`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// a.intersection_assign(&Binvec::from_raw_bytes([0b0000_0110, 0x00]));
+    /// assert_eq!(a.as_bytes(), &[0b0000_0010, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn intersection_assign(&mut self, other: &Self) {
+        kernel::bitand_assign(&mut self.inner, &other.inner); // unused bits stay 0 because both sides are 0
+    }
+
+    /// Returns the difference of `self` and `other` as a new [`Binvec`].
+    ///
+    /// This is the same as `self & !other` and only names the intent.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the bits set to `1` in `self` but not in `other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0b0000_0110, 0x00]);
+    /// assert_eq!(a.difference(&b).as_bytes(), &[0b0000_0001, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result: Binvec<L, N> = *self;
+        result.difference_assign(other);
+        result
+    }
+
+    /// Replaces `self` with the difference of `self` and `other`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// a.difference_assign(&Binvec::from_raw_bytes([0b0000_0110, 0x00]));
+    /// assert_eq!(a.as_bytes(), &[0b0000_0001, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn difference_assign(&mut self, other: &Self) {
+        kernel::andnot_assign(&mut self.inner, &other.inner); // unused bits stay 0 because they are 0 in `self`
+    }
+
+    /// Returns the symmetric difference of `self` and `other` as a new [`Binvec`].
+    ///
+    /// This is the same as `self ^ other` and only names the intent.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`Binvec`] holding the bits set to `1` in exactly one of `self` and `other`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// let b = Binvec::<12, 2>::from_raw_bytes([0b0000_0110, 0x00]);
+    /// assert_eq!(a.symmetric_difference(&b).as_bytes(), &[0b0000_0101, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut result: Binvec<L, N> = *self;
+        result.symmetric_difference_assign(other);
+        result
+    }
+
+    /// Replaces `self` with the symmetric difference of `self` and `other`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to combine with.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut a = Binvec::<12, 2>::from_raw_bytes([0b0000_0011, 0x00]);
+    /// a.symmetric_difference_assign(&Binvec::from_raw_bytes([0b0000_0110, 0x00]));
+    /// assert_eq!(a.as_bytes(), &[0b0000_0101, 0x00]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn symmetric_difference_assign(&mut self, other: &Self) {
+        kernel::bitxor_assign(&mut self.inner, &other.inner); // unused bits stay 0 because both sides are 0
+    }
+}
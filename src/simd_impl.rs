@@ -0,0 +1,60 @@
+use crate::Binvec;
+use crate::kernel::{self, Combine};
+
+
+// impl simd
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Counts the number of bits set to `1`, with AVX2 when the CPU supports it.
+    ///
+    /// This is the runtime counterpart of [`count_ones`](Binvec::count_ones), which is a `const fn` and therefore always scalar.
+    /// On `x86_64` the CPU is checked once, and 32 bytes are counted per iteration with AVX2.
+    /// On other targets, or without AVX2, or for short [`Binvec`]s, it is the same as [`count_ones`](Binvec::count_ones).
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(65_536, true);
+    /// assert_eq!(binvec.simd_count_ones(), 65_536);
+    /// assert_eq!(binvec.simd_count_ones(), binvec.count_ones());
+    /// ```
+    /// 
+    #[inline]
+    pub fn simd_count_ones(&self) -> usize {
+        kernel::count_ones_simd(&self.inner) // unused bits are always 0
+    }
+
+    /// Counts the bits that differ between two [`Binvec`]s, with AVX2 when the CPU supports it.
+    ///
+    /// This is the runtime counterpart of [`hamming_distance`](Binvec::hamming_distance), which is a `const fn` and therefore always scalar.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `other`: The [`Binvec`] to compare with.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of indices where the two [`Binvec`]s hold different bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let a = binvec!(1000, false);
+    /// let mut b = binvec!(1000, false);
+    /// b.set(1, true).unwrap();
+    /// b.set(999, true).unwrap();
+    /// assert_eq!(a.simd_hamming_distance(&b), 2);
+    /// ```
+    /// 
+    #[inline]
+    pub fn simd_hamming_distance(&self, other: &Self) -> usize {
+        kernel::count_ones_combined_simd(&self.inner, &other.inner, Combine::Xor) // unused bits are 0 on both sides
+    }
+}
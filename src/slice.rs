@@ -0,0 +1,301 @@
+use core::ops::Range;
+use crate::{Binvec, BinvecSliceIter, kernel};
+
+
+/// A borrowed view of a range of bits of a `Binvec`.
+///
+/// Unlike [`Binvec`], the offset and length of a `BinvecSlice` are only known at runtime,
+/// so one function taking a `BinvecSlice` can work on any `Binvec` or any part of one.
+/// The view does not need to start on a byte boundary.
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct BinvecSlice<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    len: usize,
+}
+
+
+impl<'a> BinvecSlice<'a> {
+    /// Creates a new `BinvecSlice` over `len` bits of `bytes` starting at bit `offset`.
+    ///
+    /// `offset + len` must be at most `bytes.len() * 8`.
+    /// 
+    pub(crate) const fn new(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        Self { bytes, offset, len }
+    }
+
+    /// Returns the length in bits of the `BinvecSlice`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of bits in the view.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, false);
+    /// assert_eq!(binvec.slice(3..10).unwrap().len(), 7);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `BinvecSlice` holds no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, false);
+    /// assert_eq!(binvec.slice(4..4).unwrap().is_empty(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit value at the given index of the view with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to retrieve, relative to the start of the view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(true)` if the bit at `index` is 1.
+    /// - `Some(false)` if the bit at `index` is 0.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(5, true).unwrap();
+    /// let slice = binvec.slice(4..8).unwrap();
+    /// assert_eq!(slice.get(1), Some(true));
+    /// assert_eq!(slice.get(4), None);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            let index: usize = self.offset + index;
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(((self.bytes[byte_index] >> bit_offset) & 1) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Counts the number of bits set to `1` in the `BinvecSlice`.
+    ///
+    /// The partial first and last bytes are masked, and the bytes in between are counted whole.
+    ///
+    /// ---
+    /// # Returns
+    /// The count of bits that are set to `1` in the view.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<20, 3> = "11110000111100001111".parse().unwrap();
+    /// assert_eq!(binvec.slice(2..10).unwrap().count_ones(), 4);
+    /// ```
+    /// 
+    pub const fn count_ones(&self) -> usize {
+        if self.len == 0 {
+            return 0;
+        }
+        let start: usize = self.offset;
+        let end: usize = self.offset + self.len;
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            return (self.bytes[start_byte] & start_mask & end_mask).count_ones() as usize;
+        }
+        let mut count: usize = (self.bytes[start_byte] & start_mask).count_ones() as usize;
+        count += kernel::count_ones(self.bytes.split_at(end_byte).0.split_at(start_byte + 1).1);
+        if end_mask != 0x00 {
+            count += (self.bytes[end_byte] & end_mask).count_ones() as usize;
+        }
+        count
+    }
+
+    /// Counts the number of bits set to `0` in the `BinvecSlice`.
+    ///
+    /// ---
+    /// # Returns
+    /// The count of bits that are set to `0` in the view.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<20, 3> = "11110000111100001111".parse().unwrap();
+    /// assert_eq!(binvec.slice(2..10).unwrap().count_zeros(), 4);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Returns a narrower view of a range of this `BinvecSlice`.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end`, relative to the start of the view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(BinvecSlice)` over the bits `start..end` of this view.
+    /// - `None` if `start` is greater than `end` or `end` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<12, 2> = "000011110000".parse().unwrap();
+    /// let slice = binvec.slice(2..12).unwrap();
+    /// assert_eq!(slice.slice(2..6).unwrap().count_ones(), 4);
+    /// assert!(slice.slice(2..11).is_none());
+    /// ```
+    /// 
+    pub const fn slice(&self, range: Range<usize>) -> Option<BinvecSlice<'a>> {
+        if range.start > range.end
+        || range.end > self.len {
+            return None;
+        }
+        Some(Self::new(self.bytes, self.offset + range.start, range.end - range.start))
+    }
+
+    /// Returns an iterator over the bits of the `BinvecSlice`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceIter`] that yields each bit as a `bool`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec: Binvec<8, 1> = "00101100".parse().unwrap();
+    /// let bits: Vec<bool> = binvec.slice(2..6).unwrap().iter().collect();
+    /// assert_eq!(bits, [true, false, true, true]);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn iter(&self) -> BinvecSliceIter<'a> {
+        BinvecSliceIter::new(*self)
+    }
+}
+
+
+// impl PartialEq
+impl PartialEq for BinvecSlice<'_> {
+    /// Compares the bits of two views, regardless of where they start in their `Binvec`s.
+    /// 
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+        && self.iter().eq(other.iter())
+    }
+}
+
+
+// impl Eq
+impl Eq for BinvecSlice<'_> {}
+
+
+// impl IntoIterator
+impl<'a> IntoIterator for BinvecSlice<'a> {
+    type Item = bool;
+    type IntoIter = BinvecSliceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+
+// impl From
+impl<'a, const L: usize, const N: usize> From<&'a Binvec<L, N>> for BinvecSlice<'a> {
+    #[inline]
+    fn from(binvec: &'a Binvec<L, N>) -> Self {
+        binvec.as_slice()
+    }
+}
+
+
+// impl slices
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Returns a [`BinvecSlice`] view of every bit of the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSlice`] of length `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// fn density(bits: BinvecSlice<'_>) -> f64 {
+    ///     bits.count_ones() as f64 / bits.len() as f64
+    /// }
+    ///
+    /// let binvec: Binvec<4, 1> = "1101".parse().unwrap();
+    /// assert_eq!(density(binvec.as_slice()), 0.75);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn as_slice(&self) -> BinvecSlice<'_> {
+        BinvecSlice::new(&self.inner, 0, L)
+    }
+
+    /// Returns a [`BinvecSlice`] view of a range of bits of the [`Binvec`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(BinvecSlice)` over the bits `start..end`.
+    /// - `None` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = binvec!(12, true);
+    /// assert_eq!(binvec.slice(3..10).unwrap().count_ones(), 7);
+    /// assert!(binvec.slice(3..13).is_none());
+    /// ```
+    /// 
+    #[inline]
+    pub const fn slice(&self, range: Range<usize>) -> Option<BinvecSlice<'_>> {
+        self.as_slice().slice(range)
+    }
+}
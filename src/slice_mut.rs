@@ -0,0 +1,437 @@
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::ops::Range;
+use crate::{Binvec, error, kernel};
+
+
+/// A mutable view of a range of bits of a `Binvec`.
+///
+/// Like [`BinvecSlice`](crate::BinvecSlice), the offset and length are only known at runtime.
+/// A `BinvecSliceMut` can be split with [`split_at_mut`](BinvecSliceMut::split_at_mut)
+/// into two views over disjoint bits that can be modified at the same time.
+///
+/// The two halves of a split own disjoint bytes, except for the byte that holds a split point
+/// that is not on a byte boundary. Only that shared byte is accessed as an [`AtomicU8`],
+/// so the halves are [`Send`] and can be modified on different threads,
+/// while every other byte is read and written directly.
+/// Splitting is only available on targets with 8-bit atomics.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut binvec = binvec!(20, false);
+/// let (mut left, mut right) = binvec.as_mut_slice().split_at_mut(9).unwrap();
+/// std::thread::scope(|scope| {
+///     scope.spawn(move || left.fill(true));
+///     scope.spawn(move || right.set(0, true).unwrap());
+/// });
+/// assert_eq!(binvec.count_ones(), 10);
+/// ```
+/// 
+#[derive(Debug)]
+pub struct BinvecSliceMut<'a> {
+    /// The first byte of the view when it is shared with the left half of a split.
+    #[cfg(target_has_atomic = "8")]
+    head: Option<&'a AtomicU8>,
+    /// The bytes owned by the view alone.
+    bytes: &'a mut [u8],
+    /// The last byte of the view when it is shared with the right half of a split.
+    #[cfg(target_has_atomic = "8")]
+    tail: Option<&'a AtomicU8>,
+    /// The bit offset of index `0` in the first byte, always less than 8.
+    offset: usize,
+    len: usize,
+}
+
+
+impl<'a> BinvecSliceMut<'a> {
+    /// Creates a new `BinvecSliceMut` over `len` bits of `bytes` starting at bit `offset`.
+    ///
+    /// `offset + len` must be at most `bytes.len() * 8`.
+    /// 
+    pub(crate) fn new(bytes: &'a mut [u8], offset: usize, len: usize) -> Self {
+        let bytes: &'a mut [u8] = &mut bytes[offset >> 3..(offset + len).div_ceil(8)]; // only the bytes the view covers
+        Self {
+            #[cfg(target_has_atomic = "8")]
+            head: None,
+            bytes,
+            #[cfg(target_has_atomic = "8")]
+            tail: None,
+            offset: offset & 0b111, // same as `offset % 8`
+            len,
+        }
+    }
+
+    /// Returns the length in bits of the `BinvecSliceMut`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of bits in the view.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.slice_mut(3..10).unwrap().len(), 7);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `BinvecSliceMut` holds no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// assert_eq!(binvec.slice_mut(4..4).unwrap().is_empty(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bit value at the given index of the view with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to retrieve, relative to the start of the view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(true)` if the bit at `index` is 1.
+    /// - `Some(false)` if the bit at `index` is 0.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.set(5, true).unwrap();
+    /// let slice = binvec.slice_mut(4..8).unwrap();
+    /// assert_eq!(slice.get(1), Some(true));
+    /// assert_eq!(slice.get(4), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            let index: usize = self.offset + index;
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(((self.load(byte_index) >> bit_offset) & 1) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit value at the given index of the view with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to set, relative to the start of the view.
+    /// - `value`: The bit value to set (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was set.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// let mut slice = binvec.slice_mut(4..8).unwrap();
+    /// slice.set(1, true).unwrap();
+    /// assert_eq!(slice.set(4, true), Err(error::IndexOutOfBounds));
+    /// assert_eq!(binvec.get(5), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        if index < self.len {
+            let index: usize = self.offset + index;
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            self.fill_masked(byte_index, 1 << bit_offset, value);
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Fills every bit of the view with the specified bit value.
+    ///
+    /// Bits outside the view are left untouched.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to fill the view with (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(20, false);
+    /// binvec.slice_mut(3..17).unwrap().fill(true);
+    /// assert_eq!(binvec.count_ones(), 14);
+    /// ```
+    /// 
+    pub fn fill(&mut self, value: bool) {
+        if self.len == 0 {
+            return;
+        }
+        let start: usize = self.offset;
+        let end: usize = self.offset + self.len;
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            self.fill_masked(start_byte, start_mask & end_mask, value);
+            return;
+        }
+        self.fill_masked(start_byte, start_mask, value);
+        let head_len: usize = self.head_len();
+        self.bytes[start_byte + 1 - head_len..end_byte - head_len].fill(if value { 0xFF } else { 0x00 }); // never shared
+        if end_mask != 0x00 {
+            self.fill_masked(end_byte, end_mask, value);
+        }
+    }
+
+    /// Counts the number of bits set to `1` in the `BinvecSliceMut`.
+    ///
+    /// ---
+    /// # Returns
+    /// The count of bits that are set to `1` in the view.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec: Binvec<20, 3> = "11110000111100001111".parse().unwrap();
+    /// assert_eq!(binvec.slice_mut(2..10).unwrap().count_ones(), 4);
+    /// ```
+    /// 
+    pub fn count_ones(&self) -> usize {
+        if self.len == 0 {
+            return 0;
+        }
+        let start: usize = self.offset;
+        let end: usize = self.offset + self.len;
+        let start_byte: usize = start >> 3; // same as `start / 8`
+        let end_byte: usize = end >> 3; // same as `end / 8`
+        let start_mask: u8 = 0xff << (start & 0b111);
+        let end_mask: u8 = (1u8 << (end & 0b111)).wrapping_sub(1); // bits before `end` in the last byte
+        if start_byte == end_byte {
+            return (self.load(start_byte) & start_mask & end_mask).count_ones() as usize;
+        }
+        let head_len: usize = self.head_len();
+        let mut count: usize = (self.load(start_byte) & start_mask).count_ones() as usize;
+        count += kernel::count_ones(&self.bytes[start_byte + 1 - head_len..end_byte - head_len]); // never shared
+        if end_mask != 0x00 {
+            count += (self.load(end_byte) & end_mask).count_ones() as usize;
+        }
+        count
+    }
+
+    /// Splits the view into two disjoint mutable views at the given index.
+    ///
+    /// The split index does not need to be on a byte boundary.
+    /// If it is not, the byte holding it is shared by both views and accessed atomically.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `mid`: The index of the first bit of the second view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some((left, right))` where `left` covers `[0, mid)` and `right` covers `[mid, len)`.
+    /// - `None` if `mid` is greater than the length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// let (mut left, mut right) = binvec.as_mut_slice().split_at_mut(5).unwrap();
+    /// left.fill(true);
+    /// right.set(0, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 6);
+    /// ```
+    /// 
+    #[cfg(target_has_atomic = "8")]
+    pub fn split_at_mut(self, mid: usize) -> Option<(BinvecSliceMut<'a>, BinvecSliceMut<'a>)> {
+        if mid > self.len {
+            return None;
+        }
+        let Self { head, bytes, tail, offset, len } = self;
+        let split: usize = offset + mid;
+        let byte_index: usize = split >> 3; // same as `split / 8`
+        let bit_offset: usize = split & 0b111; // same as `split % 8`
+        let (left_bytes, left_tail, right_head, right_bytes, right_tail): (&'a mut [u8], _, _, &'a mut [u8], _) =
+            if bit_offset != 0
+            && byte_index == 0
+            && head.is_some() {
+                (&mut [], None, head, bytes, tail) // the split is inside the shared head
+            } else {
+                let owned_index: usize = byte_index - head.is_some() as usize;
+                if owned_index > bytes.len()
+                || (bit_offset != 0 && owned_index == bytes.len()) {
+                    (bytes, tail, if bit_offset == 0 { None } else { tail }, &mut [], None) // the split is inside or after the shared tail
+                } else if bit_offset == 0 {
+                    let (left_bytes, right_bytes) = bytes.split_at_mut(owned_index);
+                    (left_bytes, None, None, right_bytes, tail)
+                } else {
+                    let (left_bytes, rest) = bytes.split_at_mut(owned_index);
+                    let (shared, right_bytes) = rest.split_first_mut().expect("the split byte is in bounds");
+                    // SAFETY: `AtomicU8` has the same size and alignment as `u8`, the byte is borrowed uniquely for `'a`,
+                    // and from here on both views only access it through this reference
+                    let shared: &'a AtomicU8 = unsafe { AtomicU8::from_ptr(shared) };
+                    (left_bytes, Some(shared), Some(shared), right_bytes, tail)
+                }
+            };
+        Some((
+            Self { head, bytes: left_bytes, tail: left_tail, offset, len: mid },
+            Self { head: right_head, bytes: right_bytes, tail: right_tail, offset: bit_offset, len: len - mid },
+        ))
+    }
+
+    /// Returns `1` if the first byte of the view is shared with the left half of a split, otherwise `0`.
+    #[inline(always)]
+    fn head_len(&self) -> usize {
+        #[cfg(target_has_atomic = "8")]
+        if self.head.is_some() {
+            return 1;
+        }
+        0
+    }
+
+    /// Returns the shared byte at `byte_index`, if that byte is shared with the other half of a split.
+    #[cfg(target_has_atomic = "8")]
+    #[inline(always)]
+    fn shared(&self, byte_index: usize) -> Option<&'a AtomicU8> {
+        if byte_index == 0
+        && self.head.is_some() {
+            self.head
+        } else if byte_index == self.head_len() + self.bytes.len() {
+            self.tail
+        } else {
+            None
+        }
+    }
+
+    /// Reads the byte at `byte_index`, counting from the first byte of the view.
+    #[inline(always)]
+    fn load(&self, byte_index: usize) -> u8 {
+        #[cfg(target_has_atomic = "8")]
+        if let Some(shared) = self.shared(byte_index) {
+            return shared.load(Ordering::Relaxed);
+        }
+        self.bytes[byte_index - self.head_len()]
+    }
+
+    /// Sets or clears the bits selected by `mask` in the byte at `byte_index`.
+    /// 
+    #[inline(always)]
+    fn fill_masked(&mut self, byte_index: usize, mask: u8, value: bool) {
+        #[cfg(target_has_atomic = "8")]
+        if let Some(shared) = self.shared(byte_index) {
+            if value {
+                shared.fetch_or(mask, Ordering::Relaxed);
+            } else {
+                shared.fetch_and(!mask, Ordering::Relaxed);
+            }
+            return;
+        }
+        let head_len: usize = self.head_len();
+        let byte: &mut u8 = &mut self.bytes[byte_index - head_len];
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+}
+
+
+// impl mutable slices
+impl<const L: usize, const N: usize> Binvec<L, N> {
+    /// Returns a [`BinvecSliceMut`] view of every bit of the [`Binvec`].
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceMut`] of length `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// fn clear_odd(mut bits: BinvecSliceMut<'_>) {
+    ///     for i in (1..bits.len()).step_by(2) {
+    ///         bits.set(i, false).unwrap();
+    ///     }
+    /// }
+    ///
+    /// let mut binvec = binvec!(12, true);
+    /// clear_odd(binvec.as_mut_slice());
+    /// assert_eq!(binvec.count_ones(), 6);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> BinvecSliceMut<'_> {
+        BinvecSliceMut::new(&mut self.inner[..], 0, L)
+    }
+
+    /// Returns a [`BinvecSliceMut`] view of a range of bits of the [`Binvec`].
+    ///
+    /// ---
+    /// # Arguments
+    /// - `range`: The half-open range of indices `start..end` to view.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(BinvecSliceMut)` over the bits `start..end`.
+    /// - `None` if `start` is greater than `end` or `end` is greater than `L`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = binvec!(12, false);
+    /// binvec.slice_mut(3..10).unwrap().fill(true);
+    /// assert_eq!(binvec.count_ones(), 7);
+    /// assert!(binvec.slice_mut(3..13).is_none());
+    /// ```
+    /// 
+    #[inline]
+    pub fn slice_mut(&mut self, range: Range<usize>) -> Option<BinvecSliceMut<'_>> {
+        if range.start > range.end
+        || range.end > L {
+            return None;
+        }
+        Some(BinvecSliceMut::new(&mut self.inner[..], range.start, range.end - range.start))
+    }
+}
@@ -0,0 +1,633 @@
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use crate::{BinvecSlice, BinvecSliceIter, BinvecSliceMut, error, kernel};
+
+
+/// A growable bit vector that keeps up to `N` bytes inline and spills to the heap beyond that.
+///
+/// Like `smallvec`, a `SmallBinvec` does not allocate while it holds at most `N * 8` bits,
+/// which keeps small flag sets cheap while still allowing the occasional large one.
+/// Once it has spilled to the heap it stays there, even if it shrinks again.
+/// The bits are packed in the same order as [`Binvec`](crate::Binvec) and [`BinvecDyn`](crate::BinvecDyn).
+///
+/// ---
+/// # Generics
+/// - `N`: The number of bytes stored inline.
+///
+/// ---
+/// # Examples
+/// ```
+/// use binvec::*;
+///
+/// let mut flags = SmallBinvec::<2>::new();
+/// flags.resize(16, true);
+/// assert_eq!(flags.spilled(), false);
+/// flags.push(true);
+/// assert_eq!(flags.spilled(), true);
+/// assert_eq!(flags.count_ones(), 17);
+/// ```
+/// 
+#[derive(Debug, Clone)]
+pub struct SmallBinvec<const N: usize> {
+    storage: Storage<N>,
+    len: usize,
+}
+
+
+/// The backing bytes of a `SmallBinvec`.
+///
+/// The inline array is zero beyond the bytes in use, and the heap vector holds exactly the bytes in use.
+/// 
+#[derive(Debug, Clone)]
+enum Storage<const N: usize> {
+    Inline([u8; N]),
+    Heap(Vec<u8>),
+}
+
+
+impl<const N: usize> SmallBinvec<N> {
+    /// Creates a new, empty `SmallBinvec` using the inline buffer.
+    ///
+    /// ---
+    /// # Returns
+    /// A `SmallBinvec` of length `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let binvec = SmallBinvec::<4>::new();
+    /// assert!(binvec.is_empty());
+    /// assert_eq!(binvec.capacity(), 32);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn new() -> Self {
+        Self { storage: Storage::Inline([0x00; N]), len: 0 }
+    }
+
+    /// Returns the length in bits of the `SmallBinvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// The number of bits stored.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.push(true);
+    /// assert_eq!(binvec.len(), 1);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `SmallBinvec` stores no bits.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length is `0`, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(SmallBinvec::<4>::new().is_empty(), true);
+    /// ```
+    /// 
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the bits have been moved to the heap.
+    ///
+    /// ---
+    /// # Returns
+    /// `true` if the length has ever exceeded `N * 8` bits, otherwise `false`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<1>::new();
+    /// binvec.resize(8, false);
+    /// assert_eq!(binvec.spilled(), false);
+    /// binvec.push(false);
+    /// assert_eq!(binvec.spilled(), true);
+    /// ```
+    /// 
+    #[inline]
+    pub const fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    /// Returns the number of bits the `SmallBinvec` can hold without reallocating.
+    ///
+    /// ---
+    /// # Returns
+    /// `N * 8` while inline, or the capacity of the heap buffer in bits once spilled.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// assert_eq!(SmallBinvec::<4>::new().capacity(), 32);
+    /// ```
+    /// 
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(_) => N << 3,
+            Storage::Heap(bytes) => bytes.capacity() << 3,
+        }
+    }
+
+    /// Returns the bit value at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to retrieve.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(true)` if the bit at `index` is 1.
+    /// - `Some(false)` if the bit at `index` is 0.
+    /// - `None` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.push(true);
+    /// assert_eq!(binvec.get(0), Some(true));
+    /// assert_eq!(binvec.get(1), None);
+    /// ```
+    /// 
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            Some(((self.as_bytes()[byte_index] >> bit_offset) & 1) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit value at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to set.
+    /// - `value`: The bit value to set (`true` for 1, `false` for 0).
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was set.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.get(3), Some(true));
+    /// assert_eq!(binvec.set(20, true), Err(error::IndexOutOfBounds));
+    /// ```
+    /// 
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), error::IndexOutOfBounds> {
+        if index < self.len {
+            let byte_index: usize = index >> 3; // same as `index / 8`
+            let bit_offset: usize = index & 0b111; // same as `index % 8`
+            let byte: &mut u8 = &mut self.bytes_mut()[byte_index];
+            if value {
+                *byte |= 1 << bit_offset;
+            } else {
+                *byte &= !(1 << bit_offset);
+            }
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Flips the bit at the given index with bounds checking.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `index`: The bit index to flip.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Ok(())` if the bit was flipped.
+    /// - `Err(IndexOutOfBounds)` if `index` is out of bounds.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, false);
+    /// binvec.toggle(3).unwrap();
+    /// assert_eq!(binvec.get(3), Some(true));
+    /// ```
+    /// 
+    #[inline]
+    pub fn toggle(&mut self, index: usize) -> Result<(), error::IndexOutOfBounds> {
+        if index < self.len {
+            self.bytes_mut()[index >> 3] ^= 1 << (index & 0b111);
+            Ok(())
+        } else {
+            Err(error::IndexOutOfBounds)
+        }
+    }
+
+    /// Appends a bit to the end of the `SmallBinvec`, spilling to the heap if the inline buffer is full.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `value`: The bit value to append.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.push(true);
+    /// binvec.push(false);
+    /// assert_eq!(binvec.len(), 2);
+    /// ```
+    /// 
+    pub fn push(&mut self, value: bool) {
+        let index: usize = self.len;
+        self.grow_to(index + 1, 0x00);
+        if value {
+            self.bytes_mut()[index >> 3] |= 1 << (index & 0b111);
+        }
+    }
+
+    /// Removes the last bit and returns it.
+    ///
+    /// ---
+    /// # Returns
+    /// - `Some(bit)` that was removed from the end.
+    /// - `None` if the `SmallBinvec` is empty.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.push(true);
+    /// assert_eq!(binvec.pop(), Some(true));
+    /// assert_eq!(binvec.pop(), None);
+    /// ```
+    /// 
+    pub fn pop(&mut self) -> Option<bool> {
+        let bit: bool = self.get(self.len.checked_sub(1)?)?;
+        self.truncate(self.len - 1);
+        Some(bit)
+    }
+
+    /// Resizes the `SmallBinvec` to the given length.
+    ///
+    /// New bits are set to `value`. When shrinking, this is the same as [`truncate`](SmallBinvec::truncate).
+    ///
+    /// ---
+    /// # Arguments
+    /// - `new_len`: The new length in bits.
+    /// - `value`: The value of the bits added when growing.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, true);
+    /// assert_eq!(binvec.count_ones(), 12);
+    /// binvec.resize(5, true);
+    /// assert_eq!(binvec.count_ones(), 5);
+    /// ```
+    /// 
+    pub fn resize(&mut self, new_len: usize, value: bool) {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        let old_len: usize = self.len;
+        if value
+        && !old_len.is_multiple_of(8) {
+            self.bytes_mut()[old_len >> 3] |= 0xFF << (old_len & 0b111); // fill the rest of the last byte
+        }
+        self.grow_to(new_len, if value { 0xFF } else { 0x00 });
+        self.clear_unused_bits();
+    }
+
+    /// Shortens the `SmallBinvec` to the given length, dropping the bits beyond it.
+    ///
+    /// This has no effect if `new_len` is not less than the current length,
+    /// and a spilled `SmallBinvec` stays on the heap.
+    ///
+    /// ---
+    /// # Arguments
+    /// - `new_len`: The new length in bits.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, true);
+    /// binvec.truncate(5);
+    /// assert_eq!(binvec.count_ones(), 5);
+    /// ```
+    /// 
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+        let byte_len: usize = new_len.div_ceil(8);
+        match &mut self.storage {
+            Storage::Inline(bytes) => bytes[byte_len..].fill(0x00),
+            Storage::Heap(bytes) => bytes.truncate(byte_len),
+        }
+        self.len = new_len;
+        self.clear_unused_bits();
+    }
+
+    /// Removes every bit. A spilled `SmallBinvec` keeps its heap buffer.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, true);
+    /// binvec.clear();
+    /// assert!(binvec.is_empty());
+    /// ```
+    /// 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Counts the number of bits set to `1`.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `1`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.count_ones(), 1);
+    /// ```
+    /// 
+    pub fn count_ones(&self) -> usize {
+        kernel::count_ones_simd(self.as_bytes()) // unused bits are always 0
+    }
+
+    /// Counts the number of bits set to `0`.
+    ///
+    /// ---
+    /// # Returns
+    /// The total count of bits that are set to `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, false);
+    /// binvec.set(3, true).unwrap();
+    /// assert_eq!(binvec.count_zeros(), 11);
+    /// ```
+    /// 
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Returns the packed bytes in use.
+    ///
+    /// ---
+    /// # Returns
+    /// The `(len + 7) / 8` bytes holding the bits. The unused bits in the last byte are always `0`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, true);
+    /// assert_eq!(binvec.as_bytes(), &[0xFF, 0x0F]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        match &self.storage {
+            Storage::Inline(bytes) => &bytes[..self.len.div_ceil(8)],
+            Storage::Heap(bytes) => bytes,
+        }
+    }
+
+    /// Returns a [`BinvecSlice`] view of every bit of the `SmallBinvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSlice`] of the same length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, true);
+    /// assert_eq!(binvec.as_slice().slice(2..6).unwrap().count_ones(), 4);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_slice(&self) -> BinvecSlice<'_> {
+        BinvecSlice::new(self.as_bytes(), 0, self.len)
+    }
+
+    /// Returns a [`BinvecSliceMut`] view of every bit of the `SmallBinvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceMut`] of the same length.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(12, false);
+    /// binvec.as_mut_slice().fill(true);
+    /// assert_eq!(binvec.count_ones(), 12);
+    /// ```
+    /// 
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> BinvecSliceMut<'_> {
+        let len: usize = self.len;
+        BinvecSliceMut::new(self.bytes_mut(), 0, len)
+    }
+
+    /// Returns an iterator over the bits of the `SmallBinvec`.
+    ///
+    /// ---
+    /// # Returns
+    /// A [`BinvecSliceIter`] that yields each bit as a `bool`.
+    ///
+    /// ---
+    /// # Examples
+    /// ```
+    /// use binvec::*;
+    ///
+    /// let mut binvec = SmallBinvec::<4>::new();
+    /// binvec.resize(3, true);
+    /// assert_eq!(binvec.iter().collect::<Vec<_>>(), [true, true, true]);
+    /// ```
+    /// 
+    #[inline]
+    pub fn iter(&self) -> BinvecSliceIter<'_> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the packed bytes in use for writing.
+    /// 
+    #[inline]
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        match &mut self.storage {
+            Storage::Inline(bytes) => &mut bytes[..self.len.div_ceil(8)],
+            Storage::Heap(bytes) => bytes,
+        }
+    }
+
+    /// Grows the length to `new_len`, filling the new bytes with `fill` and spilling to the heap if needed.
+    ///
+    /// The caller must clear the unused bits afterwards if `fill` is not `0`.
+    /// 
+    fn grow_to(&mut self, new_len: usize, fill: u8) {
+        let old_byte_len: usize = self.len.div_ceil(8);
+        let new_byte_len: usize = new_len.div_ceil(8);
+        match &mut self.storage {
+            Storage::Inline(bytes) if new_byte_len <= N => bytes[old_byte_len..new_byte_len].fill(fill),
+            Storage::Inline(bytes) => {
+                let mut heap: Vec<u8> = Vec::with_capacity(new_byte_len.max(N << 1));
+                heap.extend_from_slice(&bytes[..old_byte_len]);
+                heap.resize(new_byte_len, fill);
+                self.storage = Storage::Heap(heap);
+            }
+            Storage::Heap(bytes) => bytes.resize(new_byte_len, fill),
+        }
+        self.len = new_len;
+    }
+
+    /// Clears the unused bits beyond the length in the last byte.
+    /// 
+    #[inline(always)]
+    fn clear_unused_bits(&mut self) {
+        if !self.len.is_multiple_of(8) {
+            let mask: u8 = (1u8 << (self.len % 8)) - 1;
+            let last: usize = self.len >> 3;
+            self.bytes_mut()[last] &= mask;
+        }
+    }
+}
+
+
+// impl Default
+impl<const N: usize> Default for SmallBinvec<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+// impl PartialEq
+impl<const N: usize> PartialEq for SmallBinvec<N> {
+    /// Compares the bits, regardless of whether either side has spilled to the heap.
+    /// 
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+        && self.as_bytes() == other.as_bytes()
+    }
+}
+
+
+// impl Eq
+impl<const N: usize> Eq for SmallBinvec<N> {}
+
+
+// impl Hash
+impl<const N: usize> Hash for SmallBinvec<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.as_bytes().hash(state);
+    }
+}
+
+
+// impl FromIterator
+impl<const N: usize> FromIterator<bool> for SmallBinvec<N> {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut binvec: SmallBinvec<N> = Self::new();
+        binvec.extend(iter);
+        binvec
+    }
+}
+
+
+// impl Extend
+impl<const N: usize> Extend<bool> for SmallBinvec<N> {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+
+// impl IntoIterator
+impl<'a, const N: usize> IntoIterator for &'a SmallBinvec<N> {
+    type Item = bool;
+    type IntoIter = BinvecSliceIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}